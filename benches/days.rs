@@ -0,0 +1,188 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Benchmarks the parse step and both parts of every day against its real input.
+//! Run with `cargo bench`, e.g. `cargo bench -- day08` for one day.
+use advent_of_code::input::INPUTS_DIR_VAR;
+use advent_of_code::perf::{self, Comparison, Entry};
+use advent_of_code::{
+    bench, guard, load_input, solutions, InputSource, RunConfig, Status, ANSI_BOLD, ANSI_ITALIC,
+    ANSI_RESET, PARSE_STEP,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use std::{env, process};
+
+struct Args {
+    year: u16,
+    /// Time budget per benchmark. `cargo bench` passes `--bench`, without it (e.g. under
+    /// `cargo test --benches`) every benchmark runs once as a smoke test.
+    bench: Option<Duration>,
+    /// Compare against the latest run of this revision instead of the latest run overall.
+    baseline: Option<String>,
+    /// Only benchmarks whose name contains this, e.g. `day08` or `part2`.
+    filter: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = advent_of_code::parse_year(&mut args)?;
+    let bench_time: Option<u64> = args.opt_value_from_str("--bench-time")?;
+    let bench = args
+        .contains("--bench")
+        .then(|| bench_time.map_or(bench::DEFAULT_BUDGET, Duration::from_millis));
+    Ok(Args {
+        year,
+        bench,
+        baseline: args.opt_value_from_str("--baseline")?,
+        filter: args.opt_free_from_str()?,
+    })
+}
+
+/// `day08/parse`, `day08/part1` or `day08/part2`.
+fn bench_name(day: u8, part: u8) -> String {
+    match part {
+        PARSE_STEP => format!("day{day:02}/parse"),
+        part => format!("day{day:02}/part{part}"),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+    let Some(solutions) = solutions(args.year) else {
+        eprintln!("No solutions registered for {}.", args.year);
+        process::exit(1);
+    };
+
+    let history_path = perf::bench_history_path(args.year);
+    let history = perf::read_history(&history_path).unwrap_or_else(|e| {
+        eprintln!("Failed to read benchmark history: {e}");
+        process::exit(1);
+    });
+    let config = RunConfig {
+        bench: args.bench,
+        ..RunConfig::default()
+    };
+    let run = perf::next_run(&history);
+    let (rev, date) = (perf::git_rev(), perf::now_utc());
+    let mut entries = Vec::new();
+    let mut failed = 0;
+
+    for solution in solutions {
+        let selected = |part: u8| {
+            let name = bench_name(solution.day, part);
+            args.filter
+                .as_ref()
+                .is_none_or(|filter| name.contains(filter))
+        };
+        let parts: Vec<_> = solution
+            .parts()
+            .into_iter()
+            .filter(|(part, _)| selected(*part))
+            .collect();
+        let bench_parse = solution.parses && selected(PARSE_STEP);
+        if parts.is_empty() && !bench_parse {
+            continue;
+        }
+
+        let input = match load_input(solution.year, solution.day, &InputSource::Default) {
+            Ok(input) => input,
+            Err(_) => {
+                println!(
+                    "day{:02}: skipped {ANSI_ITALIC}(no input){ANSI_RESET}",
+                    solution.day
+                );
+                continue;
+            }
+        };
+
+        // parsed once for all parts, and only benchmarked if the parse step is selected.
+        // a panicking step fails on its own, without a time limit as budgets can be long.
+        let parse_config = if bench_parse {
+            config.clone()
+        } else {
+            RunConfig::default()
+        };
+        let (parsed, parse_result) =
+            guard::parse_guarded(solution, input.into(), Arc::new(parse_config), None);
+        let mut steps = Vec::new();
+        if bench_parse {
+            steps.push((PARSE_STEP, parse_result));
+        }
+        let shared = Arc::new(config.clone());
+        for (part, runner) in parts {
+            let result = guard::part_guarded(runner, &parsed, shared.clone(), None);
+            steps.push((part, result));
+        }
+
+        for (part, result) in steps {
+            let name = bench_name(solution.day, part);
+            match result.status {
+                Status::Solved => {}
+                Status::Unsolved => {
+                    println!("{name}: skipped {ANSI_ITALIC}(unsolved){ANSI_RESET}");
+                    continue;
+                }
+                _ => {
+                    failed += 1;
+                    let error = result.error.as_deref().unwrap_or("failed");
+                    println!("{name}: {ANSI_BOLD}FAIL{ANSI_RESET} {error}");
+                    continue;
+                }
+            }
+
+            let Some(stats) = result.stats else {
+                println!("{name}: ok");
+                continue;
+            };
+            let comparison = Comparison {
+                day: solution.day,
+                part,
+                baseline: perf::latest(&history, solution.day, part, args.baseline.as_deref())
+                    .map(|entry| entry.duration),
+                current: stats.median,
+            };
+            let change = match comparison.change() {
+                Some(change) if comparison.regressed(perf::DEFAULT_THRESHOLD) => {
+                    format!(
+                        " {ANSI_ITALIC}({:+.1}%){ANSI_RESET} {ANSI_BOLD}REGRESSED{ANSI_RESET}",
+                        change * 100.0
+                    )
+                }
+                Some(change) => format!(" {ANSI_ITALIC}({:+.1}%){ANSI_RESET}", change * 100.0),
+                None => String::new(),
+            };
+            println!(
+                "{name:<12} median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} iterations{change}",
+                stats.median, stats.mean, stats.stddev, stats.min, stats.iterations
+            );
+
+            entries.push(Entry {
+                run,
+                rev: rev.clone(),
+                date: date.clone(),
+                day: solution.day,
+                part,
+                duration: stats.median,
+            });
+        }
+    }
+
+    // nothing is recorded if nothing was measured, e.g. under `cargo test --benches`, and inputs
+    // from elsewhere are not comparable with the recorded ones.
+    if !entries.is_empty() && env::var_os(INPUTS_DIR_VAR).is_none() {
+        if let Err(e) = perf::append_history(&history_path, &entries) {
+            eprintln!("Failed to record benchmarks: {e}");
+        }
+    }
+    if failed > 0 {
+        process::exit(1);
+    }
+}
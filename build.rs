@@ -0,0 +1,133 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Generates one test per example that has an answers sidecar (`src/examples/YYYY/NN*.toml`),
+//! ignored until the sidecar records an answer. Days include them with
+//! `advent_of_code::example_tests!(YYYY, N)`.
+//! Also generates one golden test per day for `tests/golden.rs`, ignored while the day has
+//! no real input or no recorded answers.
+use std::{
+    collections::BTreeSet,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Mirrors `advent_of_code::input::INPUTS_DIR_VAR`, build scripts can't use the library.
+const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    example_tests(&out_dir);
+    golden_tests(&out_dir);
+}
+
+fn example_tests(out_dir: &Path) {
+    println!("cargo:rerun-if-changed=src/examples");
+
+    let out_dir = out_dir.join("examples");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let Ok(years) = fs::read_dir("src/examples") else {
+        return;
+    };
+
+    for year_dir in years.filter_map(Result::ok) {
+        let Some(year) = year_dir
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u16>().ok())
+        else {
+            continue;
+        };
+
+        let mut sidecars: Vec<String> = fs::read_dir(year_dir.path())
+            .unwrap()
+            .filter_map(|entry| {
+                let file_name = entry.ok()?.file_name().into_string().ok()?;
+                Some(file_name.strip_suffix(".toml")?.to_string())
+            })
+            .collect();
+        sidecars.sort();
+
+        for day in 1..=25 {
+            let prefix = format!("{day:02}");
+            let tests: String = sidecars
+                .iter()
+                .filter(|name| {
+                    *name == &prefix
+                        || name
+                            .strip_prefix(&prefix)
+                            .is_some_and(|suffix| suffix.starts_with('-'))
+                })
+                .map(|name| {
+                    // a scaffolded sidecar has no answers yet, and would pass without checking any.
+                    let sidecar = year_dir.path().join(format!("{name}.toml"));
+                    let ignore = if has_answers(&sidecar) {
+                        String::new()
+                    } else {
+                        let reason = format!("no answers recorded in {}", sidecar.display());
+                        format!("#[ignore = {reason:?}]\n")
+                    };
+                    format!(
+                        "#[test]\n{ignore}fn example_{}() {{\n    advent_of_code::examples::check::<Day>(\"{name}\");\n}}\n",
+                        name.replace('-', "_")
+                    )
+                })
+                .collect();
+
+            fs::write(out_dir.join(format!("{year}-{day}.rs")), tests).unwrap();
+        }
+    }
+}
+
+fn golden_tests(out_dir: &Path) {
+    println!("cargo:rerun-if-changed=src/answers");
+    println!("cargo:rerun-if-env-changed={INPUTS_DIR_VAR}");
+    let inputs_dir = match env::var_os(INPUTS_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from("src/inputs"),
+    };
+    println!("cargo:rerun-if-changed={}", inputs_dir.display());
+
+    let years: BTreeSet<u16> = [inputs_dir.as_path(), Path::new("src/answers")]
+        .into_iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+        .collect();
+
+    let mut tests = String::new();
+    for year in years {
+        for day in 1..=25 {
+            let input = inputs_dir.join(format!("{year}/{day:02}.txt"));
+            let answers = PathBuf::from(format!("src/answers/{year}/{day:02}.toml"));
+            // the same rule as `input::has_input`, which build scripts cannot call.
+            let has_input = fs::read_to_string(&input).is_ok_and(|input| !input.trim().is_empty());
+            let skip = if !has_input {
+                Some(format!("no input in {}", input.display()))
+            } else if !has_answers(&answers) {
+                Some(format!("no answers recorded in {}", answers.display()))
+            } else {
+                None
+            };
+
+            let ignore = skip.map_or(String::new(), |reason| format!("#[ignore = {reason:?}]\n"));
+            tests += &format!(
+                "#[test]\n{ignore}fn golden_{year}_{day:02}() {{\n    check({year}, {day});\n}}\n"
+            );
+        }
+    }
+
+    fs::write(out_dir.join("golden.rs"), tests).unwrap();
+}
+
+/// Whether an answers file records at least one part, without parsing it fully.
+fn has_answers(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|content| {
+        content.lines().any(|line| {
+            let line = line.trim_start();
+            line.starts_with("part_one") || line.starts_with("part_two")
+        })
+    })
+}
@@ -0,0 +1,314 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{input::data_dir, PartResult};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Known-good answers for a day's real input, stored in `src/answers/YYYY/NN.toml`:
+///
+/// ```toml
+/// part_one = "514579"
+/// part_two = "241861950"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    /// Parses the `key = value` subset of TOML the answer files use.
+    /// Values may be quoted strings, with `\"`, `\\`, `\n` and `\t` escapes, or bare integers.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = Answers::default();
+
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected `key = value`", i + 1));
+            };
+
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => unquote(quoted).map_err(|e| format!("line {}: {e}", i + 1))?,
+                None if is_integer(value) => value.to_string(),
+                None => return Err(format!("line {}: expected a string or integer", i + 1)),
+            };
+
+            match key.trim() {
+                "part_one" => answers.part_one = Some(value),
+                "part_two" => answers.part_two = Some(value),
+                key => return Err(format!("line {}: unknown key \"{key}\"", i + 1)),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part_one = Some(answer.to_string()),
+            2 => self.part_two = Some(answer.to_string()),
+            _ => {}
+        }
+    }
+
+    /// Inverse of [`Answers::parse`], always writing values as strings.
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        for (key, value) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(value) = value {
+                toml.push_str(&format!("{}\n", line(key, value)));
+            }
+        }
+        toml
+    }
+}
+
+/// Key of `part` in an answers file.
+fn key(part: u8) -> Option<&'static str> {
+    match part {
+        1 => Some("part_one"),
+        2 => Some("part_two"),
+        _ => None,
+    }
+}
+
+fn line(key: &str, value: &str) -> String {
+    format!("{key} = \"{}\"", escape(value))
+}
+
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The value of a basic TOML string, `quoted` is everything after its opening quote.
+fn unquote(quoted: &str) -> Result<String, String> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().is_empty() => return Ok(value),
+            '"' => return Err("unexpected text after string".into()),
+            '\\' => value.push(match chars.next() {
+                Some('"') => '"',
+                Some('\\') => '\\',
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some(c) => return Err(format!("unsupported escape \"\\{c}\"")),
+                None => break,
+            }),
+            c => value.push(c),
+        }
+    }
+    Err("unterminated string".into())
+}
+
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    data_dir("answers", year).join(format!("{day:02}.toml"))
+}
+
+/// Returns `Ok(None)` if no answers have been recorded for `day`.
+pub fn read_answers(year: u16, day: u8) -> Result<Option<Answers>, String> {
+    read_answers_file(&answers_path(year, day))
+}
+
+/// Like [`read_answers`], for an answers file outside of `src/answers`.
+pub fn read_answers_file(path: &Path) -> Result<Option<Answers>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Answers::parse(&contents)
+            .map(Some)
+            .map_err(|e| format!("{}: {e}", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+/// Sets `part`'s answer in the answers file `contents`. Only its line changes, or one is
+/// appended, so comments and the other part are kept as they are.
+pub fn set_answer(contents: &str, part: u8, answer: &str) -> String {
+    let Some(key) = key(part) else {
+        return contents.to_string();
+    };
+    let is_key = |line: &str| {
+        line.split_once('=')
+            .is_some_and(|(line_key, _)| line_key.trim() == key)
+    };
+
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+    match lines.iter_mut().find(|existing| is_key(existing)) {
+        Some(existing) => *existing = line(key, answer),
+        None => lines.push(line(key, answer)),
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Records `part`'s answer in the answers file at `path`, creating it if needed.
+pub fn write_answer(path: &Path, part: u8, answer: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    fs::write(path, set_answer(&contents, part, answer))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, result: &PartResult) -> Self {
+        match (expected, &result.answer) {
+            (None, _) => Verdict::Missing,
+            (Some(_), None) if result.status == crate::Status::MissingInput => Verdict::Missing,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Status;
+    use std::time::Duration;
+
+    fn result(answer: Option<&str>, status: Status) -> PartResult {
+        PartResult {
+            answer: answer.map(String::from),
+            elapsed: Duration::ZERO,
+            status,
+            stats: None,
+            alloc: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("# day 21\npart_one = 5\npart_two = \"mxmxvkd,sqjhc\"\n");
+        assert_eq!(
+            answers,
+            Ok(Answers {
+                part_one: Some("5".into()),
+                part_two: Some("mxmxvkd,sqjhc".into()),
+            })
+        );
+
+        assert_eq!(Answers::parse(""), Ok(Answers::default()));
+        assert!(Answers::parse("part_three = 1").is_err());
+        assert!(Answers::parse("part_one = \"5").is_err());
+        assert!(Answers::parse("part_one = five").is_err());
+        assert!(Answers::parse("part_one = \"5\\\"").is_err());
+        assert!(Answers::parse("part_one = ").is_err());
+        assert!(Answers::parse("part_one = --").is_err());
+        assert!(Answers::parse("part_one = 1-2").is_err());
+        assert_eq!(
+            Answers::parse("part_one = -12")
+                .unwrap()
+                .part_one
+                .as_deref(),
+            Some("-12")
+        );
+        assert!(Answers::parse("part_one = \"5\" 6").is_err());
+        assert!(Answers::parse("part_one = \"\\x\"").is_err());
+    }
+
+    #[test]
+    fn test_to_toml_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(2, "mxmxvkd,sqjhc");
+        assert_eq!(answers.to_toml(), "part_two = \"mxmxvkd,sqjhc\"\n");
+
+        answers.set(1, "5");
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+
+        let mut answers = Answers::default();
+        answers.set(1, r#"say "hi" \ bye"#);
+        assert_eq!(
+            answers.to_toml(),
+            "part_one = \"say \\\"hi\\\" \\\\ bye\"\n"
+        );
+        assert_eq!(Answers::parse(&answers.to_toml()), Ok(answers));
+    }
+
+    #[test]
+    fn test_set_answer() {
+        let contents = "# answers for your real input.\npart_two = 8\n";
+        let updated = set_answer(contents, 1, "5");
+        assert_eq!(updated, format!("{contents}part_one = \"5\"\n"));
+        assert_eq!(
+            set_answer(&updated, 2, "9"),
+            "# answers for your real input.\npart_two = \"9\"\npart_one = \"5\"\n"
+        );
+        assert_eq!(
+            set_answer("# part_one = \"\"", 1, "5"),
+            "# part_one = \"\"\npart_one = \"5\"\n"
+        );
+        assert_eq!(set_answer("", 2, "x"), "part_two = \"x\"\n");
+    }
+
+    #[test]
+    fn test_check() {
+        let solved = result(Some("5"), Status::Solved);
+        assert_eq!(Verdict::check(Some("5"), &solved), Verdict::Pass);
+        assert_eq!(
+            Verdict::check(Some("6"), &solved),
+            Verdict::Fail {
+                expected: "6".into()
+            }
+        );
+        assert_eq!(Verdict::check(None, &solved), Verdict::Missing);
+
+        let unsolved = result(None, Status::Unsolved);
+        assert_eq!(
+            Verdict::check(Some("5"), &unsolved),
+            Verdict::Fail {
+                expected: "5".into()
+            }
+        );
+
+        let missing = result(None, Status::MissingInput);
+        assert_eq!(Verdict::check(Some("5"), &missing), Verdict::Missing);
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(1);
+
+/// Share of the budget spent warming up caches and the branch predictor before sampling.
+const WARM_UP_RATIO: u32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort_unstable();
+
+        let len = samples.len();
+        let median = if len % 2 == 0 {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        let nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / len as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / len as f64;
+
+        Self {
+            iterations: len as u32,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Warms up `func`, then samples it until `budget` is used up. Always takes at least one sample.
+/// Returns the output of the last call alongside the timing statistics.
+pub fn bench<I: ?Sized, T>(func: impl Fn(&I) -> T, input: &I, budget: Duration) -> (T, BenchStats) {
+    let warm_up = budget / WARM_UP_RATIO;
+    let timer = Instant::now();
+    loop {
+        func(input);
+        if timer.elapsed() >= warm_up {
+            break;
+        }
+    }
+
+    let mut samples = Vec::new();
+    let timer = Instant::now();
+    let output = loop {
+        let sample = Instant::now();
+        let output = func(input);
+        samples.push(sample.elapsed());
+
+        if timer.elapsed() >= budget {
+            break output;
+        }
+    };
+
+    (output, BenchStats::from_samples(&mut samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = BenchStats::from_samples(&mut samples);
+
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev, Duration::from_nanos(1118));
+    }
+
+    #[test]
+    fn test_bench_takes_at_least_one_sample() {
+        let (output, stats) = bench(str::len, "abc", Duration::ZERO);
+        assert_eq!(output, 3);
+        assert_eq!(stats.iterations, 1);
+    }
+}
@@ -0,0 +1,8 @@
+use advent_of_code::year2020::day08::{parse, part_one, part_two, Day};
+
+fn main() {
+    let input = &advent_of_code::read_input(2020, 8);
+    let instructions = advent_of_code::parse!(parse, input);
+    advent_of_code::solve!(1, part_one, &instructions);
+    advent_of_code::solve!(2, part_two, &instructions);
+}
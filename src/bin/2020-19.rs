@@ -0,0 +1,7 @@
+use advent_of_code::year2020::day19::{part_one, part_two, Day};
+
+fn main() {
+    let input = &advent_of_code::read_input(2020, 19);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
@@ -0,0 +1,8 @@
+use advent_of_code::year2020::day20::{parse, part_one, part_two, Day};
+
+fn main() {
+    let input = &advent_of_code::read_input(2020, 20);
+    let tiles = advent_of_code::parse!(parse, input);
+    advent_of_code::solve!(1, part_one, &tiles);
+    advent_of_code::solve!(2, part_two, &tiles);
+}
@@ -0,0 +1,284 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    answers::{read_answers, Answers},
+    input::has_input,
+    input_path,
+    output::{self, Record},
+    puzzle, solution_path, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    process::{self, Command},
+    time::Duration,
+};
+
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+const HELP: &str = "<day> select · r run · a run all · p puzzle · q quit";
+
+/// State of one part, shown as one cell of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PartState {
+    /// the day has no solution or no input yet.
+    Unavailable,
+    NotRun,
+    Solved,
+    Unsolved,
+    /// wrong answer, error, panic, timeout or a solution that did not run at all.
+    Failing,
+}
+
+impl PartState {
+    fn symbol(self) -> String {
+        match self {
+            PartState::Unavailable => format!("{ANSI_DIM}·{ANSI_RESET}"),
+            PartState::NotRun => "?".into(),
+            PartState::Solved => format!("{ANSI_GREEN}★{ANSI_RESET}"),
+            PartState::Unsolved => format!("{ANSI_YELLOW}☆{ANSI_RESET}"),
+            PartState::Failing => format!("{ANSI_RED}✗{ANSI_RESET}"),
+        }
+    }
+}
+
+/// Outcome of running a day's binary against its real input.
+enum Run {
+    Finished([Option<Record>; 2]),
+    /// the binary did not build or exited with an error.
+    Failed(String),
+}
+
+struct Day {
+    year: u16,
+    day: u8,
+    last_run: Option<Run>,
+}
+
+impl Day {
+    fn bin(&self) -> String {
+        format!("{}-{:02}", self.year, self.day)
+    }
+
+    fn has_solution(&self) -> bool {
+        solution_path(self.year, self.day).exists()
+    }
+
+    fn has_input(&self) -> bool {
+        has_input(&input_path("inputs", self.year, self.day))
+    }
+
+    /// Answers are re-read on every draw, so `cargo submit` in another terminal shows up.
+    fn expected(&self) -> Option<Answers> {
+        read_answers(self.year, self.day).ok().flatten()
+    }
+
+    fn record(&self, part: u8) -> Option<&Record> {
+        match &self.last_run {
+            Some(Run::Finished(records)) => records[part as usize - 1].as_ref(),
+            _ => None,
+        }
+    }
+
+    fn state(&self, part: u8, expected: Option<&Answers>) -> PartState {
+        if !self.has_solution() || !self.has_input() {
+            return PartState::Unavailable;
+        }
+        let record = match &self.last_run {
+            None => return PartState::NotRun,
+            Some(Run::Failed(_)) => return PartState::Failing,
+            Some(Run::Finished(_)) => self.record(part),
+        };
+        let expected = expected.and_then(|answers| answers.get(part));
+
+        match record {
+            None => PartState::Failing,
+            Some(record) => match record.status {
+                Status::Solved if expected.is_some_and(|e| record.answer.as_deref() != Some(e)) => {
+                    PartState::Failing
+                }
+                Status::Solved => PartState::Solved,
+                Status::Unsolved => PartState::Unsolved,
+                Status::MissingInput => PartState::Unavailable,
+                Status::Error | Status::Panicked | Status::TimedOut => PartState::Failing,
+            },
+        }
+    }
+
+    fn run(&mut self) {
+        let output = Command::new("cargo")
+            .args(["run", "--quiet", "--release", "--bin", &self.bin()])
+            .args(["--", "--format", "json"])
+            .output();
+
+        self.last_run = Some(match output {
+            Ok(output) if output.status.success() => {
+                let mut records = [None, None];
+                for line in String::from_utf8_lossy(&output.stdout).lines() {
+                    if let Some(record @ Record { part: 1..=2, .. }) = output::from_json(line) {
+                        let part = record.part as usize;
+                        records[part - 1] = Some(record);
+                    }
+                }
+                Run::Finished(records)
+            }
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let reason = stderr.lines().rev().find(|line| !line.trim().is_empty());
+                Run::Failed(reason.unwrap_or("exited with an error").trim().to_string())
+            }
+            Err(e) => Run::Failed(format!("could not run cargo: {e}")),
+        });
+    }
+}
+
+fn draw(days: &[Day], selected: usize, message: &str) {
+    print!("{ANSI_CLEAR}");
+    println!("{ANSI_BOLD}🎄 Advent of Code {}{ANSI_RESET}", days[0].year);
+    println!();
+
+    for row in days.chunks(5) {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|day| {
+                let index = day.day as usize - 1;
+                let expected = day.expected();
+                let parts = [1, 2].map(|part| day.state(part, expected.as_ref()).symbol());
+                let label = format!("{:02}", day.day);
+                let label = if index == selected {
+                    format!("{ANSI_BOLD}[{label}]{ANSI_RESET}")
+                } else {
+                    format!(" {label} ")
+                };
+                format!("{label} {}{} ", parts[0], parts[1])
+            })
+            .collect();
+        println!("  {}", cells.join(" "));
+    }
+
+    println!();
+    draw_details(&days[selected]);
+    println!();
+    if !message.is_empty() {
+        println!("{message}");
+    }
+    println!("{ANSI_ITALIC}{HELP}{ANSI_RESET}");
+    print!("> ");
+    let _ = io::stdout().flush();
+}
+
+fn draw_details(day: &Day) {
+    let yes_no = |present: bool| if present { "yes" } else { "missing" };
+    println!("{ANSI_BOLD}Day {:02}{ANSI_RESET}", day.day);
+    println!("  solution: {}", yes_no(day.has_solution()));
+    println!("  input:    {}", yes_no(day.has_input()));
+
+    if let Some(Run::Failed(reason)) = &day.last_run {
+        println!("  {ANSI_RED}failed to run:{ANSI_RESET} {reason}");
+    }
+
+    let expected = day.expected();
+    for part in [1, 2] {
+        let state = day.state(part, expected.as_ref());
+        let answer = match day.record(part) {
+            Some(record) => {
+                let answer = record.answer.as_deref().unwrap_or(record.status.as_str());
+                format!("{answer} ({})", format_duration(record.duration))
+            }
+            None => "-".into(),
+        };
+        let expected = expected
+            .as_ref()
+            .and_then(|answers| answers.get(part))
+            .map(|answer| format!(", expected {answer}"))
+            .unwrap_or_default();
+        println!("  part {part}: {} {answer}{expected}", state.symbol());
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+fn show_puzzle(day: &Day) -> Result<(), String> {
+    let path = puzzle::puzzle_path(day.year, day.day);
+    let markdown = fs::read_to_string(&path).map_err(|_| {
+        format!(
+            "No puzzle stored for day {:02}, run `cargo download {}` first.",
+            day.day, day.day
+        )
+    })?;
+    print!("{ANSI_CLEAR}{}", puzzle::render(&markdown));
+    println!();
+    print!("{ANSI_ITALIC}press enter to go back{ANSI_RESET}");
+    let _ = io::stdout().flush();
+    Ok(())
+}
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let year = match advent_of_code::parse_year(&mut args) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut days: Vec<Day> = (1..=25)
+        .map(|day| Day {
+            year,
+            day,
+            last_run: None,
+        })
+        .collect();
+    let mut selected = 0;
+    let mut message = String::new();
+    let mut showing_puzzle = false;
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        if !showing_puzzle {
+            draw(&days, selected, &message);
+        }
+        showing_puzzle = false;
+        message.clear();
+
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+
+        match line.trim() {
+            "" => {}
+            "q" => break,
+            "r" => {
+                println!("Running day {:02}...", days[selected].day);
+                days[selected].run();
+            }
+            "a" => {
+                for day in days.iter_mut() {
+                    if day.has_solution() && day.has_input() {
+                        println!("Running day {:02}...", day.day);
+                        day.run();
+                    }
+                }
+            }
+            "p" => match show_puzzle(&days[selected]) {
+                Ok(()) => showing_puzzle = true,
+                Err(e) => message = e,
+            },
+            command => match command.parse::<usize>() {
+                Ok(day @ 1..=25) => selected = day - 1,
+                _ => message = format!("Unknown command \"{command}\"."),
+            },
+        }
+    }
+    println!();
+}
@@ -2,19 +2,21 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::provider::{self, ProviderError};
+use advent_of_code::{input_path, puzzle};
+use std::{fs, path::Path, process};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    let year = advent_of_code::parse_year(&mut args)?;
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year,
     })
 }
 
@@ -27,20 +29,37 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let provider = provider::from_env();
+    let input_path = input_path("inputs", args.year, args.day);
+    let puzzle_path = puzzle::puzzle_path(args.year, args.day);
+
+    let result = provider
+        .fetch_input(args.year, args.day)
+        .and_then(|input| write(&input_path, &input))
+        .and_then(|_| provider.fetch_description(args.year, args.day))
+        .and_then(|puzzle| write(&puzzle_path, &puzzle));
+
+    if let Err(e) = result {
+        eprintln!("Failed to download day {:02}: {e}", args.day);
         process::exit(1);
     }
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
-            process::exit(1);
-        }
-    }
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ProviderError> {
+    let io_error = |reason| ProviderError::Io {
+        path: path.to_path_buf(),
+        reason,
+    };
+    fs::create_dir_all(path.parent().unwrap_or(Path::new("."))).map_err(io_error)?;
+    fs::write(path, contents).map_err(io_error)
 }
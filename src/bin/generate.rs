@@ -0,0 +1,80 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{generate::generate, input_path, rng::Rng};
+use std::{fs, process};
+
+struct Args {
+    day: u8,
+    year: u16,
+    /// the same seed always generates the same input. random if not given.
+    seed: Option<u64>,
+    scale: usize,
+    /// write to the day's input file instead of printing the input.
+    write: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = advent_of_code::parse_year(&mut args)?;
+    let seed = args.opt_value_from_str("--seed")?;
+    let scale = args.opt_value_from_str("--scale")?.unwrap_or(1);
+    let write = args.contains("--write");
+    Ok(Args {
+        day: args.free_from_str()?,
+        year,
+        seed,
+        scale,
+        write,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let seed = args.seed.unwrap_or_else(Rng::random_seed);
+    let Some(input) = generate(args.year, args.day, &mut Rng::new(seed), args.scale) else {
+        eprintln!(
+            "There is no input generator for day {:02} of {}.",
+            args.day, args.year
+        );
+        process::exit(1);
+    };
+
+    if !args.write {
+        print!("{input}");
+        eprintln!("Generated with --seed {seed}.");
+        return;
+    }
+
+    // `cargo scaffold` leaves an empty input file behind, only real content is protected.
+    let path = input_path("inputs", args.year, args.day);
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        eprintln!(
+            "Not overwriting \"{}\", it already has an input. Remove it first.",
+            path.display()
+        );
+        process::exit(1);
+    }
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, &input));
+    match written {
+        Ok(()) => println!(
+            "Wrote an input generated with --seed {seed} to \"{}\".",
+            path.display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
+}
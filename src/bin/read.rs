@@ -2,19 +2,20 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
-use std::process;
+use advent_of_code::{provider, puzzle};
+use std::{fs, process};
 
 struct Args {
     day: u8,
-    year: Option<u16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    let year = advent_of_code::parse_year(&mut args)?;
     Ok(Args {
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year,
     })
 }
 
@@ -27,19 +28,16 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+    // prefer the puzzle stored by `cargo download`, it works offline.
+    if let Ok(markdown) = fs::read_to_string(puzzle::puzzle_path(args.year, args.day)) {
+        print!("{}", puzzle::render(&markdown));
+        return;
     }
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    match provider::from_env().fetch_description(args.year, args.day) {
+        Ok(markdown) => print!("{}", puzzle::render(&markdown)),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("Failed to fetch day {:02}: {e}", args.day);
             process::exit(1);
         }
     }
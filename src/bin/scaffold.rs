@@ -2,113 +2,272 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::{
+    answers::answers_path, input::data_dir, input_path, puzzle, solution_path, template,
+};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, create_dir_all, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r#"pub fn part_one(input: &str) -> Option<usize> {
-    None
-}
+const EXAMPLE_ANSWERS: &str = "\
+# expected answers for the example, checked by `cargo test`.
+# part_one = \"\"
+# part_two = \"\"
+";
 
-pub fn part_two(input: &str) -> Option<usize> {
-    None
-}
+const ANSWERS: &str = "\
+# answers for your real input, checked by `cargo verify` and recorded by `cargo submit`.
+";
+
+/// The day's binary, which only runs the solution module on the real input.
+const BINARY: &str = "\
+use advent_of_code::year{{year}}::day{{day_padded}}::{part_one, part_two, Day};
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
+    let input = &advent_of_code::read_input({{year}}, {{day}});
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
+";
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+struct Args {
+    day: u8,
+    year: u16,
+    template: String,
+    /// print what would be created without touching the file system.
+    dry_run: bool,
+    /// overwrite an existing solution. data files are never overwritten.
+    force: bool,
 }
-"#;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let year = advent_of_code::parse_year(&mut args)?;
+    let template = args
+        .opt_value_from_str("--template")?
+        .unwrap_or_else(|| template::DEFAULT_TEMPLATE.into());
+    let dry_run = args.contains("--dry-run");
+    let force = args.contains("--force");
+    Ok(Args {
+        day: args.free_from_str()?,
+        year,
+        template,
+        dry_run,
+        force,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Renders the solution module from the template and the binary that runs it.
+fn render_module(args: &Args) -> Result<(String, String), String> {
+    let path = template::template_path(&args.template);
+    let template = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read template \"{}\": {e}", path.display()))?;
+
+    // use the downloaded puzzle's title if there is one.
+    let title = fs::read_to_string(puzzle::puzzle_path(args.year, args.day))
+        .ok()
+        .and_then(|markdown| puzzle::title(&markdown))
+        .unwrap_or_else(|| format!("Day {}", args.day));
+
+    let (day, day_padded, year) = (
+        args.day.to_string(),
+        format!("{:02}", args.day),
+        args.year.to_string(),
+    );
+    let values: [(&str, &str); 4] = [
+        ("day", &day),
+        ("day_padded", &day_padded),
+        ("year", &year),
+        ("title", &title),
+    ];
+    let module = template::render(&template, &values)
+        .map_err(|e| format!("invalid template \"{}\": {e}", path.display()))?;
+    let binary = template::render(BINARY, &values)?;
+    Ok((module, binary))
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Adds `declaration` to the module at `path`, in order among the lines starting with `prefix`.
+/// Returns `false` if it is already declared.
+fn declare(path: &Path, declaration: &str, prefix: &str) -> io::Result<bool> {
+    let contents = fs::read_to_string(path)?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&declaration) {
+        return Ok(false);
+    }
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with(prefix) && *line > declaration)
+        .or_else(|| {
+            let last = lines.iter().rposition(|line| line.starts_with(prefix))?;
+            Some(last + 1)
+        })
+        .unwrap_or(lines.len());
+    lines.insert(position, declaration);
+
+    fs::write(path, lines.join("\n") + "\n")?;
+    Ok(true)
+}
+
+/// Writes `contents` to `path`, only replacing an existing file if `overwrite` is set.
+/// Returns `false` if the file was left alone.
+fn write_file(path: &Path, contents: &str, overwrite: bool) -> io::Result<bool> {
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir)?;
+    }
+
+    let mut options = OpenOptions::new();
+    options.write(true);
+    if overwrite {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+
+    match options.open(path) {
+        Ok(mut file) => file.write_all(contents.as_bytes()).map(|_| true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            eprintln!(
+                "Need to specify a day (as integer). example: `cargo scaffold 7 --year 2020`"
+            );
             process::exit(1);
         }
     };
-
+    let Args { day, year, .. } = args;
+    // checked before anything is written, a day 0 or 26 would only leave stray files behind.
+    if !(1..=25).contains(&day) {
+        eprintln!("Invalid day {day}, expected 1 to 25.");
+        process::exit(1);
+    }
     let day_padded = format!("{day:02}");
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    let (module, binary) = render_module(&args).unwrap_or_else(|e| {
+        eprintln!("Failed to render module: {e}");
+        process::exit(1);
+    });
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
-        }
-    };
+    let src = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+    let module_path = solution_path(year, day);
+    let binary_path = src.join("bin").join(format!("{year}-{day_padded}.rs"));
+    let year_path = src.join(format!("year{year}.rs"));
+    let lib_path = src.join("lib.rs");
+    let new_year = !year_path.exists();
+    // the solution is only replaced with `--force`, and nothing is written without it.
+    let refused = module_path.exists() && !args.force;
+
+    let files = [
+        ("module", module_path, module.as_str(), args.force),
+        ("binary", binary_path, binary.as_str(), args.force),
+        ("input", input_path("inputs", year, day), "", false),
+        ("example", input_path("examples", year, day), "", false),
+        (
+            "example answers",
+            data_dir("examples", year).join(format!("{day_padded}.toml")),
+            EXAMPLE_ANSWERS,
+            false,
+        ),
+        ("answers", answers_path(year, day), ANSWERS, false),
+    ];
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    let module_path = &files[0].1;
+    if args.dry_run {
+        for (kind, path, _, overwrite) in &files {
+            match (path.exists(), overwrite) {
+                (true, false) if path == module_path => println!(
+                    "Would stop at existing {kind} file \"{}\", pass `--force` to overwrite it",
+                    path.display()
+                ),
+                (false, _) => println!("Would create {kind} file \"{}\"", path.display()),
+                (true, true) => println!("Would overwrite {kind} file \"{}\"", path.display()),
+                (true, false) => println!("Would keep existing {kind} file \"{}\"", path.display()),
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+        println!(
+            "Would declare `day{day_padded}` in \"{}\"",
+            year_path.display()
+        );
+        if new_year {
+            println!("Would declare `year{year}` in \"{}\"", lib_path.display());
         }
+        println!("---");
+        print!("{module}");
+        return;
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
-        }
+    if refused {
+        eprintln!(
+            "Failed to create module file: \"{}\" already exists. Pass `--force` to overwrite it.",
+            module_path.display()
+        );
+        process::exit(1);
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for (kind, path, contents, overwrite) in &files {
+        match write_file(path, contents, *overwrite) {
+            Ok(true) => println!("Created {kind} file \"{}\"", path.display()),
+            Ok(false) => println!("Kept existing {kind} file \"{}\"", path.display()),
+            Err(e) => {
+                eprintln!("Failed to create {kind} file \"{}\": {e}", path.display());
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+    }
+
+    // a new year's module starts out with only its doc comment.
+    let year_doc =
+        format!("//! Solutions for {year}. Each day also has a binary in `src/bin` that runs it on its own.\n");
+    if let Err(e) = write_file(&year_path, &year_doc, false) {
+        eprintln!(
+            "Failed to create year module \"{}\": {e}",
+            year_path.display()
+        );
+        process::exit(1);
+    }
+
+    let declarations = [
+        (
+            year_path,
+            format!("pub mod day{day_padded};"),
+            "pub mod day",
+        ),
+        (lib_path, format!("pub mod year{year};"), "pub mod year"),
+    ];
+    for (path, declaration, prefix) in &declarations {
+        let module = declaration
+            .trim_start_matches("pub mod ")
+            .trim_end_matches(';');
+        match declare(path, declaration, prefix) {
+            Ok(true) => println!("Declared `{module}` in \"{}\"", path.display()),
+            Ok(false) => println!(
+                "Kept existing declaration of `{module}` in \"{}\"",
+                path.display()
+            ),
+            Err(e) => {
+                eprintln!(
+                    "Failed to declare `{module}` in \"{}\": {e}",
+                    path.display()
+                );
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        &day_padded, year
+    );
+    println!(
+        "🎄 Add `year{year}::day{day_padded}::Day` to the registry in \"src/lib.rs\" to include it in `cargo all`."
     );
 }
@@ -0,0 +1,66 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{ffi::OsString, process, process::Command};
+
+struct Args {
+    day: u8,
+    year: u16,
+    release: bool,
+    /// `--alloc-stats` builds with the feature of the same name.
+    alloc_stats: bool,
+    /// forwarded to the solution binary, e.g. `--bench` or `--input <path>`.
+    rest: Vec<OsString>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let release = args.contains("--release");
+    let alloc_stats = args.contains("--alloc-stats");
+    let year = advent_of_code::parse_year(&mut args)?;
+    let day = args.free_from_str()?;
+    let rest = args
+        .finish()
+        .into_iter()
+        .filter(|arg| arg != "--")
+        .collect();
+
+    Ok(Args {
+        day,
+        year,
+        release,
+        alloc_stats,
+        rest,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let bin = format!("{}-{:02}", args.year, args.day);
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--bin", &bin]);
+    if args.release {
+        cmd.arg("--release");
+    }
+    if args.alloc_stats {
+        cmd.args(["--features", "alloc-stats"]);
+    }
+    cmd.arg("--").args(&args.rest);
+
+    match cmd.status() {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(e) => {
+            eprintln!("Failed to run solution \"{bin}\": {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{
+    examples::is_example_of, input::data_dir, input_path, output, solution_path, ANSI_BOLD,
+    ANSI_ITALIC, ANSI_RESET,
+};
+use std::{
+    fs,
+    path::PathBuf,
+    process::{self, Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+struct Args {
+    day: u8,
+    year: u16,
+    release: bool,
+    interval: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = advent_of_code::parse_year(&mut args)?;
+    let release = args.contains("--release");
+    let interval = args
+        .opt_value_from_str("--interval")?
+        .map_or(DEFAULT_INTERVAL, Duration::from_millis);
+    Ok(Args {
+        day: args.free_from_str()?,
+        year,
+        release,
+        interval,
+    })
+}
+
+/// The solution, its input and all of its examples including their answer sidecars.
+fn watched_files(year: u16, day: u8) -> Vec<PathBuf> {
+    let mut files = vec![solution_path(year, day), input_path("inputs", year, day)];
+
+    if let Ok(entries) = fs::read_dir(data_dir("examples", year)) {
+        let mut examples: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|stem| is_example_of(stem, day))
+            })
+            .collect();
+        examples.sort();
+        files.extend(examples);
+    }
+
+    files
+}
+
+/// Modification times of the watched files, `None` for missing ones.
+fn snapshot(files: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    files
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Runs the tests of the day's module in the library.
+fn run_tests(year: u16, day: u8) -> bool {
+    Command::new("cargo")
+        .args([
+            "test",
+            "--quiet",
+            "--lib",
+            &format!("year{year}::day{day:02}::"),
+        ])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Runs the solution against the real input, returning each part's answer.
+fn run_solution(bin: &str, release: bool) -> Option<[Option<String>; 2]> {
+    let mut cmd = Command::new("cargo");
+    cmd.args(["run", "--quiet", "--bin", bin]);
+    if release {
+        cmd.arg("--release");
+    }
+    let output = cmd
+        .args(["--", "--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    let mut answers = [None, None];
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(record @ output::Record { part: 1..=2, .. }) = output::from_json(line) {
+            answers[record.part as usize - 1] = record.answer;
+        }
+    }
+    Some(answers)
+}
+
+fn print_diff(previous: Option<&[Option<String>; 2]>, answers: &[Option<String>; 2]) {
+    for (i, answer) in answers.iter().enumerate() {
+        let before = previous.map(|previous| &previous[i]);
+        let shown = answer.as_deref().unwrap_or("not solved");
+        let change = match before {
+            None => String::new(),
+            Some(before) if before == answer => format!(" {ANSI_ITALIC}(unchanged){ANSI_RESET}"),
+            Some(before) => format!(
+                " {ANSI_ITALIC}(was {}){ANSI_RESET}",
+                before.as_deref().unwrap_or("not solved")
+            ),
+        };
+        println!("{ANSI_BOLD}Part {}:{ANSI_RESET} {shown}{change}", i + 1);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let bin = format!("{}-{:02}", args.year, args.day);
+    let mut last_snapshot = Vec::new();
+    let mut previous: Option<[Option<String>; 2]> = None;
+
+    println!("🎄 Watching day {:02}, press Ctrl+C to stop.", args.day);
+
+    loop {
+        // re-listed every time, so examples added while watching are picked up.
+        let current = snapshot(&watched_files(args.year, args.day));
+        if current != last_snapshot {
+            last_snapshot = current;
+
+            println!("----------");
+            println!("{ANSI_BOLD}| Day {:02} |{ANSI_RESET}", args.day);
+            println!("----------");
+
+            if !run_tests(args.year, args.day) {
+                println!("Example tests failed, skipping the real input.");
+            } else if let Some(answers) = run_solution(&bin, args.release) {
+                print_diff(previous.as_ref(), &answers);
+                previous = Some(answers);
+            } else {
+                println!("Could not run the real input.");
+            }
+        }
+
+        thread::sleep(args.interval);
+    }
+}
@@ -0,0 +1,162 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fmt, str::FromStr};
+
+/// Why a part did not produce an answer. Return `Result<T, SolveError>` from `part_one` /
+/// `part_two` instead of panicking, `solve!` prints the error as a diagnostic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// A piece of the input could not be parsed. `line` and `column` are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    /// The input parsed, but does not describe a solvable puzzle.
+    InvalidInput(String),
+    /// No answer was found, same as returning `None`.
+    Unsolved,
+}
+
+impl SolveError {
+    /// A parse error for `text`, a slice of `input` such as a line or token of it.
+    /// The position is taken from where `text` lies within `input`, or where it first occurs
+    /// if it was copied out of it.
+    pub fn parse(input: &str, text: &str, reason: impl Into<String>) -> Self {
+        let offset = Some(text.as_ptr())
+            .filter(|text| input.as_bytes().as_ptr_range().contains(text))
+            .map(|text| text as usize - input.as_ptr() as usize)
+            .filter(|&offset| offset + text.len() <= input.len() && input.is_char_boundary(offset))
+            .or_else(|| input.find(text))
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        SolveError::Parse {
+            line,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid(reason: impl Into<String>) -> Self {
+        SolveError::InvalidInput(reason.into())
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse {
+                line,
+                column,
+                text,
+                reason,
+            } => write!(
+                f,
+                "parse error at line {line}, column {column}: {reason} (\"{text}\")"
+            ),
+            SolveError::InvalidInput(reason) => write!(f, "invalid input: {reason}"),
+            SolveError::Unsolved => write!(f, "not solved"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Parses `text`, a slice of `input`, reporting failures as [`SolveError::Parse`].
+pub fn parse_in<T>(input: &str, text: &str) -> Result<T, SolveError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse()
+        .map_err(|e: T::Err| SolveError::parse(input, text, e.to_string()))
+}
+
+/// What a solver may return: `Option<T>` or `Result<T, SolveError>`.
+pub trait Answer<T> {
+    fn into_result(self) -> Result<T, SolveError>;
+}
+
+impl<T> Answer<T> for Option<T> {
+    fn into_result(self) -> Result<T, SolveError> {
+        self.ok_or(SolveError::Unsolved)
+    }
+}
+
+impl<T> Answer<T> for Result<T, SolveError> {
+    fn into_result(self) -> Result<T, SolveError> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_position() {
+        let input = "1-3 a: abcde\n1-x b: cdefg\n";
+        let line = input.lines().nth(1).unwrap();
+        let error = SolveError::parse(input, &line[2..3], "invalid digit");
+        assert_eq!(
+            error,
+            SolveError::Parse {
+                line: 2,
+                column: 3,
+                text: "x".into(),
+                reason: "invalid digit".into(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "parse error at line 2, column 3: invalid digit (\"x\")"
+        );
+
+        // copied text is located by searching for it.
+        let copied = String::from("cdefg");
+        assert!(matches!(
+            SolveError::parse(input, &copied, "?"),
+            SolveError::Parse {
+                line: 2,
+                column: 8,
+                ..
+            }
+        ));
+
+        // text that is neither within nor part of `input` points at its start.
+        let elsewhere = String::from("1-x b: cdefg\nmore");
+        assert!(matches!(
+            SolveError::parse(input, &elsewhere[13..], "?"),
+            SolveError::Parse {
+                line: 1,
+                column: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_in() {
+        let input = "12 x4";
+        assert_eq!(parse_in::<u32>(input, &input[..2]), Ok(12));
+        assert_eq!(
+            parse_in::<u32>(input, &input[3..]).unwrap_err().to_string(),
+            "parse error at line 1, column 4: invalid digit found in string (\"x4\")"
+        );
+    }
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Some(1).into_result(), Ok(1));
+        assert_eq!(None::<u8>.into_result(), Err(SolveError::Unsolved));
+        let invalid: Result<u8, _> = Err(SolveError::invalid("empty"));
+        assert_eq!(invalid.into_result(), Err(SolveError::invalid("empty")));
+    }
+}
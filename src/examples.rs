@@ -0,0 +1,139 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{answers::Answers, input::data_dir, Solution};
+use std::{fs, io};
+
+/// An example input from `src/examples/YYYY`, e.g. `04.txt`, `04-2.txt` or `04-part2-a.txt`.
+/// Expected answers are read from a sidecar with the same name, e.g. `04-2.toml`,
+/// which uses the same format as the answers store.
+#[derive(Debug)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+pub fn is_example_of(name: &str, day: u8) -> bool {
+    let prefix = format!("{day:02}");
+    name == prefix
+        || name
+            .strip_prefix(&prefix)
+            .is_some_and(|suffix| suffix.starts_with('-'))
+}
+
+/// Names of all examples for `day`, sorted so that `NN` comes first.
+pub fn example_names(year: u16, day: u8) -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = fs::read_dir(data_dir("examples", year))?
+        .filter_map(|entry| {
+            let file_name = entry.ok()?.file_name().into_string().ok()?;
+            let name = file_name.strip_suffix(".txt")?;
+            is_example_of(name, day).then(|| name.to_string())
+        })
+        .collect();
+
+    names.sort();
+    Ok(names)
+}
+
+pub fn load_example(year: u16, name: &str) -> Result<Example, String> {
+    let dir = data_dir("examples", year);
+
+    let input_path = dir.join(format!("{name}.txt"));
+    let input = fs::read_to_string(&input_path)
+        .map_err(|e| format!("could not read \"{}\": {e}", input_path.display()))?;
+
+    let sidecar_path = dir.join(format!("{name}.toml"));
+    let answers = match fs::read_to_string(&sidecar_path) {
+        Ok(contents) => {
+            Answers::parse(&contents).map_err(|e| format!("{}: {e}", sidecar_path.display()))?
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::default(),
+        Err(e) => {
+            return Err(format!(
+                "could not read \"{}\": {e}",
+                sidecar_path.display()
+            ))
+        }
+    };
+
+    Ok(Example {
+        name: name.to_string(),
+        input,
+        answers,
+    })
+}
+
+pub fn load_examples(year: u16, day: u8) -> Result<Vec<Example>, String> {
+    example_names(year, day)
+        .map_err(|e| format!("could not list examples: {e}"))?
+        .iter()
+        .map(|name| load_example(year, name))
+        .collect()
+}
+
+/// Runs `S` against the example `name`, panicking with every part that does not match its
+/// expected answer. Used by the tests generated by [`example_tests!`](crate::example_tests).
+pub fn check<S: Solution>(name: &str) {
+    let example = load_example(S::YEAR, name).unwrap_or_else(|e| panic!("{e}"));
+
+    // parts without an expected answer are skipped, some examples only apply to one part.
+    let mismatches: Vec<String> = [1, 2]
+        .into_iter()
+        .filter_map(|part| {
+            let expected = example.answers.get(part)?;
+            let answer = S::parse(&example.input).and_then(|input| match part {
+                1 => S::part_one(&input).map(|a| a.to_string()),
+                _ => S::part_two(&input).map(|a| a.to_string()),
+            });
+            match answer {
+                Ok(answer) if answer == expected => None,
+                Ok(answer) => Some(format!(
+                    "example {name} part {part}: expected {expected}, got {answer}"
+                )),
+                Err(e) => Some(format!(
+                    "example {name} part {part}: expected {expected}, got {e}"
+                )),
+            }
+        })
+        .collect();
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+/// Expands to one `#[test]` per example of a day that has an answers sidecar.
+/// The tests are generated by `build.rs`, call this inside a day's `tests` module.
+#[macro_export]
+macro_rules! example_tests {
+    ($year:literal, $day:literal) => {
+        include!(concat!(
+            env!("OUT_DIR"),
+            "/examples/",
+            $year,
+            "-",
+            $day,
+            ".rs"
+        ));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_example_of() {
+        assert!(is_example_of("04", 4));
+        assert!(is_example_of("04-2", 4));
+        assert!(is_example_of("04-part2-a", 4));
+        assert!(!is_example_of("14", 4));
+        assert!(!is_example_of("042", 4));
+    }
+
+    #[test]
+    fn test_example_names() {
+        assert_eq!(example_names(2020, 4).unwrap(), ["04", "04-2"]);
+        assert_eq!(example_names(2020, 1).unwrap(), ["01"]);
+    }
+}
@@ -0,0 +1,1368 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::rng::Rng;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
+
+/// Generates an input for `day` of `year` that follows the puzzle's rules, or `None` if there
+/// is no generator for the day. `scale` multiplies the size of the input, the same seed and
+/// scale always give the same input.
+pub fn generate(year: u16, day: u8, rng: &mut Rng, scale: usize) -> Option<String> {
+    let generator: fn(&mut Rng, usize) -> String = match (year, day) {
+        (2020, 1) => day01,
+        (2020, 2) => day02,
+        (2020, 3) => day03,
+        (2020, 4) => day04,
+        (2020, 5) => day05,
+        (2020, 6) => day06,
+        (2020, 7) => day07,
+        (2020, 8) => day08,
+        (2020, 9) => day09,
+        (2020, 10) => day10,
+        (2020, 11) => day11,
+        (2020, 12) => day12,
+        (2020, 13) => day13,
+        (2020, 14) => day14,
+        (2020, 15) => day15,
+        (2020, 16) => day16,
+        (2020, 17) => day17,
+        (2020, 18) => day18,
+        (2020, 19) => day19,
+        (2020, 20) => day20,
+        (2020, 21) => day21,
+        (2020, 22) => day22,
+        (2020, 23) => day23,
+        (2020, 24) => day24,
+        (2020, 25) => day25,
+        _ => return None,
+    };
+    Some(generator(rng, scale.max(1)))
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// Side of a grid with `scale` times the area of a `base` sided one.
+fn scaled_side(base: usize, scale: usize) -> usize {
+    (base as f64 * (scale as f64).sqrt()).round() as usize
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.range(0..=25) as u8) as char
+}
+
+fn word(rng: &mut Rng, length: usize) -> String {
+    (0..length).map(|_| letter(rng)).collect()
+}
+
+fn grid(rng: &mut Rng, width: usize, height: usize, filled: u8, density: f64) -> String {
+    lines((0..height).map(|_| {
+        (0..width)
+            .map(|_| if rng.chance(density) { filled } else { b'.' } as char)
+            .collect()
+    }))
+}
+
+/// Expense report: exactly one pair and one triple add up to 2020.
+fn day01(rng: &mut Rng, scale: usize) -> String {
+    let specials = loop {
+        let a = rng.range(1011..=1500);
+        let (c, d) = (rng.range(100..=500), rng.range(100..=500));
+        let specials = [a, 2020 - a, c, d, 2020 - c - d];
+        let pairs = (0..5)
+            .flat_map(|i| (i + 1..5).map(move |j| (i, j)))
+            .filter(|&(i, j)| specials[i] + specials[j] == 2020)
+            .count();
+        let triples = (0..5)
+            .flat_map(|i| (i + 1..5).flat_map(move |j| (j + 1..5).map(move |k| (i, j, k))))
+            .filter(|&(i, j, k)| specials[i] + specials[j] + specials[k] == 2020)
+            .count();
+        if pairs == 1 && triples == 1 && specials.iter().collect::<HashSet<_>>().len() == 5 {
+            break specials;
+        }
+    };
+
+    // any two fillers add up to more than 2020, they only need to avoid completing a sum
+    // with the small numbers.
+    let small: Vec<usize> = specials.iter().copied().filter(|&n| n < 1011).collect();
+    let mut excluded: HashSet<usize> = specials.iter().copied().collect();
+    for (i, a) in small.iter().enumerate() {
+        excluded.insert(2020 - a);
+        for b in &small[i + 1..] {
+            excluded.insert(2020 - a - b);
+        }
+    }
+
+    let mut numbers = specials.to_vec();
+    while numbers.len() < 200 * scale {
+        let filler = rng.range(1011..=1999);
+        if !excluded.contains(&filler) {
+            numbers.push(filler);
+        }
+    }
+    rng.shuffle(&mut numbers);
+    lines(numbers.into_iter().map(|n| n.to_string()))
+}
+
+/// Password policies, about a third of the passwords favour the policy's letter.
+fn day02(rng: &mut Rng, scale: usize) -> String {
+    lines((0..1000 * scale).map(|_| {
+        let letter_of_policy = letter(rng);
+        let min = rng.range(1..=10);
+        let max = rng.range(min + 1..=min + 10);
+        let length = rng.range(max / 2 + 1..=20);
+        let password: String = (0..length)
+            .map(|_| {
+                if rng.chance(0.3) {
+                    letter_of_policy
+                } else {
+                    letter(rng)
+                }
+            })
+            .collect();
+        format!("{min}-{max} {letter_of_policy}: {password}")
+    }))
+}
+
+/// Tree map, 31 columns wide.
+fn day03(rng: &mut Rng, scale: usize) -> String {
+    grid(rng, 31, 323 * scale, b'#', 0.25)
+}
+
+const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+fn hex(rng: &mut Rng, length: usize) -> String {
+    (0..length)
+        .map(|_| b"0123456789abcdef"[rng.range(0..=15)] as char)
+        .collect()
+}
+
+fn digits(rng: &mut Rng, length: usize) -> String {
+    (0..length)
+        .map(|_| (b'0' + rng.range(0..=9) as u8) as char)
+        .collect()
+}
+
+fn either(rng: &mut Rng, a: RangeInclusive<usize>, b: RangeInclusive<usize>) -> String {
+    let range = if rng.chance(0.5) { a } else { b };
+    rng.range(range).to_string()
+}
+
+fn passport_field(rng: &mut Rng, key: &str, valid: bool) -> String {
+    match (key, valid) {
+        ("byr", true) => either(rng, 1920..=1960, 1961..=2002),
+        ("byr", false) => either(rng, 1900..=1919, 2003..=2010),
+        ("iyr", true) => either(rng, 2010..=2015, 2016..=2020),
+        ("iyr", false) => either(rng, 2000..=2009, 2021..=2030),
+        ("eyr", true) => either(rng, 2020..=2025, 2026..=2030),
+        ("eyr", false) => either(rng, 2010..=2019, 2031..=2040),
+        ("hgt", true) if rng.chance(0.5) => format!("{}cm", rng.range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.range(59..=76)),
+        ("hgt", false) => match rng.range(0..=2) {
+            0 => format!("{}cm", either(rng, 100..=149, 194..=200)),
+            1 => format!("{}in", either(rng, 40..=58, 77..=90)),
+            _ => rng.range(59..=193).to_string(),
+        },
+        ("hcl", true) => format!("#{}", hex(rng, 6)),
+        ("hcl", false) if rng.chance(0.5) => hex(rng, 6),
+        ("hcl", false) => format!("#{}", word(rng, 6)),
+        ("ecl", true) => rng.choose(&EYE_COLORS).to_string(),
+        ("ecl", false) => rng
+            .choose(&["xry", "zzz", "gmt", "blue", "#fa2b1c"])
+            .to_string(),
+        ("pid", true) => digits(rng, 9),
+        ("pid", false) if rng.chance(0.5) => {
+            let length = *rng.choose(&[8, 10]);
+            digits(rng, length)
+        }
+        ("pid", false) => format!("#{}", hex(rng, 6)),
+        (_, _) => rng.range(100..=350).to_string(),
+    }
+}
+
+/// Passports: valid ones, ones with an invalid value and ones missing a required field.
+fn day04(rng: &mut Rng, scale: usize) -> String {
+    const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+    let passports: Vec<String> = (0..290 * scale)
+        .map(|_| {
+            let mut keys = REQUIRED.to_vec();
+            if rng.chance(0.5) {
+                keys.push("cid");
+            }
+            let invalid = match rng.range(0..=2) {
+                0 => None,
+                1 => Some(*rng.choose(&REQUIRED)),
+                _ => {
+                    let missing = *rng.choose(&REQUIRED);
+                    keys.retain(|key| *key != missing);
+                    None
+                }
+            };
+            rng.shuffle(&mut keys);
+
+            let mut passport = String::new();
+            for (i, key) in keys.iter().enumerate() {
+                if i > 0 {
+                    passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+                }
+                let value = passport_field(rng, key, invalid != Some(*key));
+                passport.push_str(&format!("{key}:{value}"));
+            }
+            passport
+        })
+        .collect();
+    passports.join("\n\n") + "\n"
+}
+
+/// Boarding passes for a contiguous range of seats with one seat missing. A plane only has
+/// 1024 seats, so this tops out at 1000 passes.
+fn day05(rng: &mut Rng, scale: usize) -> String {
+    let count = (850 * scale).min(1000);
+    let first = rng.range(1..=1023 - count);
+    let missing = rng.range(first + 1..=first + count - 1);
+    let mut passes: Vec<String> = (first..=first + count)
+        .filter(|&id| id != missing)
+        .map(|id| {
+            (0..10)
+                .map(|bit| {
+                    let set = id & (1 << (9 - bit)) != 0;
+                    match (bit < 7, set) {
+                        (true, false) => 'F',
+                        (true, true) => 'B',
+                        (false, false) => 'L',
+                        (false, true) => 'R',
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rng.shuffle(&mut passes);
+    lines(passes)
+}
+
+/// Customs answers, groups share some of their answers.
+fn day06(rng: &mut Rng, scale: usize) -> String {
+    let groups: Vec<String> = (0..490 * scale)
+        .map(|_| {
+            let common: Vec<char> = ('a'..='z').filter(|_| rng.chance(0.3)).collect();
+            let people = rng.range(1..=5);
+            let answers: Vec<String> = (0..people)
+                .map(|_| {
+                    let mut answers: Vec<char> = ('a'..='z')
+                        .filter(|c| common.contains(c) || rng.chance(0.15))
+                        .collect();
+                    if answers.is_empty() {
+                        answers.push(letter(rng));
+                    }
+                    rng.shuffle(&mut answers);
+                    answers.into_iter().collect()
+                })
+                .collect();
+            answers.join("\n")
+        })
+        .collect();
+    groups.join("\n\n") + "\n"
+}
+
+const BAG_ADJECTIVES: [&str; 18] = [
+    "light", "dark", "bright", "muted", "faded", "dotted", "vibrant", "posh", "pale", "dim",
+    "drab", "wavy", "plaid", "striped", "clear", "dull", "mirrored", "dashed",
+];
+const BAG_COLORS: [&str; 33] = [
+    "red",
+    "orange",
+    "white",
+    "yellow",
+    "gold",
+    "olive",
+    "plum",
+    "blue",
+    "black",
+    "green",
+    "lime",
+    "purple",
+    "teal",
+    "tan",
+    "maroon",
+    "cyan",
+    "indigo",
+    "violet",
+    "magenta",
+    "salmon",
+    "silver",
+    "gray",
+    "brown",
+    "beige",
+    "bronze",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "fuchsia",
+    "lavender",
+    "aqua",
+    "turquoise",
+    "tomato",
+];
+
+/// Bag rules. Bags are arranged in layers and only contain bags of the next layer, which
+/// keeps the nesting as shallow as in the real puzzle. Shiny gold sits in the middle.
+fn day07(rng: &mut Rng, scale: usize) -> String {
+    const LAYERS: usize = 8;
+    const SHINY_GOLD_LAYER: usize = 3;
+
+    let count = 594 * scale;
+    let mut seen = HashSet::from(["shiny gold".to_string()]);
+    let mut names: Vec<String> = BAG_ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            BAG_COLORS
+                .iter()
+                .map(move |color| format!("{adjective} {color}"))
+        })
+        .collect();
+    rng.shuffle(&mut names);
+    names.retain(|name| seen.insert(name.clone()));
+    while names.len() < count - 1 {
+        let length = rng.range(4..=7);
+        let name = format!("{} {}", word(rng, length), rng.choose(&BAG_COLORS));
+        if seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    names.truncate(count - 1);
+
+    let layer_size = count / LAYERS;
+    let mut layers: Vec<Vec<String>> = names.chunks(layer_size).map(|c| c.to_vec()).collect();
+    // the remainder joins the last layer instead of forming a layer of its own.
+    if layers.len() > LAYERS {
+        let remainder = layers.pop().unwrap();
+        layers[LAYERS - 1].extend(remainder);
+    }
+    layers[SHINY_GOLD_LAYER][0] = "shiny gold".into();
+
+    let mut rules = Vec::with_capacity(count);
+    for (depth, layer) in layers.iter().enumerate() {
+        for bag in layer {
+            let must_have_contents = bag == "shiny gold" || *bag == layers[SHINY_GOLD_LAYER - 1][0];
+            let is_leaf = depth == LAYERS - 1 || (!must_have_contents && rng.chance(0.15));
+            if is_leaf {
+                rules.push(format!("{bag} bags contain no other bags."));
+                continue;
+            }
+            let mut inner = layers[depth + 1].clone();
+            rng.shuffle(&mut inner);
+            // at least one bag holds shiny gold, so part one has something to count.
+            if *bag == layers[SHINY_GOLD_LAYER - 1][0] {
+                let position = inner.iter().position(|b| b == "shiny gold").unwrap();
+                inner.swap(0, position);
+            }
+            let contents: Vec<String> = inner[..rng.range(1..=4)]
+                .iter()
+                .map(|inner| match rng.range(1..=5) {
+                    1 => format!("1 {inner} bag"),
+                    amount => format!("{amount} {inner} bags"),
+                })
+                .collect();
+            rules.push(format!("{bag} bags contain {}.", contents.join(", ")));
+        }
+    }
+    rng.shuffle(&mut rules);
+    lines(rules)
+}
+
+fn signed(rng: &mut Rng, max: usize) -> isize {
+    let value = rng.range(1..=max) as isize;
+    if rng.chance(0.5) {
+        value
+    } else {
+        -value
+    }
+}
+
+/// Boot code that loops, with exactly one `nop` or `jmp` that makes it terminate when
+/// flipped. The looping path never falls into the block of instructions at the end, only the
+/// fixed instruction leads there. Instructions off the path jump back onto it.
+fn day08(rng: &mut Rng, scale: usize) -> String {
+    let count = 600 * scale;
+    let exit_length = rng.range(5..=20);
+    let region = count - exit_length;
+    // the last instruction before the exit block, only on the path if it is the fix.
+    let escape = region - 1;
+    let fix_is_jmp = rng.chance(0.5);
+
+    let mut on_path = vec![false; region];
+    let mut path = vec![0];
+    on_path[0] = true;
+    let length = rng.range(region / 4..=region / 2);
+    let escape_at = rng.range(1..=length - 2);
+    while path.len() < length {
+        let current = *path.last().unwrap();
+        let free = |i: usize, on_path: &[bool]| !on_path[i] && (fix_is_jmp || i != escape);
+        let next = if fix_is_jmp && path.len() == escape_at && !on_path[escape] {
+            escape
+        } else if current != escape && free(current + 1, &on_path) && rng.chance(0.7) {
+            current + 1
+        } else {
+            loop {
+                let next = rng.range(0..=region - 1);
+                if free(next, &on_path) {
+                    break next;
+                }
+            }
+        };
+        on_path[next] = true;
+        path.push(next);
+    }
+
+    let mut program: Vec<Option<(&str, isize)>> = vec![None; count];
+    let last = *path.last().unwrap();
+    program[last] = Some(("jmp", *rng.choose(&path) as isize - last as isize));
+    let mut falls_through = Vec::new();
+    for step in path.windows(2) {
+        let (current, next) = (step[0], step[1]);
+        // the escape always jumps, flipping it falls into the exit block.
+        program[current] = Some(if current == escape || next != current + 1 {
+            ("jmp", next as isize - current as isize)
+        } else {
+            falls_through.push(current);
+            match rng.range(0..=9) {
+                0..=5 => ("acc", signed(rng, 50)),
+                6..=8 => ("nop", rng.range(0..=region - 1) as isize - current as isize),
+                _ => ("jmp", 1),
+            }
+        });
+    }
+    if !fix_is_jmp {
+        let fix = *rng.choose(&falls_through);
+        program[fix] = Some(("nop", rng.range(region..=count - 1) as isize - fix as isize));
+    }
+    for (i, instruction) in program.iter_mut().enumerate() {
+        if instruction.is_none() {
+            *instruction = Some(if i >= region {
+                if rng.chance(0.7) {
+                    ("acc", signed(rng, 50))
+                } else {
+                    ("nop", signed(rng, 50))
+                }
+            } else {
+                ("jmp", *rng.choose(&path) as isize - i as isize)
+            });
+        }
+    }
+
+    lines(
+        program
+            .into_iter()
+            .flatten()
+            .map(|(operation, argument)| format!("{operation} {argument:+}")),
+    )
+}
+
+/// XMAS numbers, headed by the preamble length. One number is not the sum of two of the
+/// preamble before it, and a range of earlier numbers adds up to it.
+fn day09(rng: &mut Rng, scale: usize) -> String {
+    let preamble = 25 * scale;
+    let count = 1000 * scale;
+    let invalid_at = rng.range(count * 3 / 5..=count * 4 / 5);
+
+    let mut pool: Vec<u64> = (1..=2 * preamble as u64).collect();
+    rng.shuffle(&mut pool);
+    let mut numbers = pool[..preamble].to_vec();
+    for i in preamble..count {
+        let window = &numbers[i - preamble..i];
+        let number = if i == invalid_at {
+            loop {
+                let start = rng.range(preamble..=i / 2);
+                let length = rng.range(2..=20);
+                let target: u64 = numbers[start..start + length].iter().sum();
+                let is_sum = window
+                    .iter()
+                    .enumerate()
+                    .any(|(j, a)| window[j + 1..].iter().any(|b| a + b == target));
+                if !is_sum {
+                    break target;
+                }
+            }
+        } else {
+            // summing the oldest numbers doubles them about once per preamble, like the
+            // real input. picking anywhere in the window overflows long before the end.
+            let oldest = (preamble / 5).max(2);
+            let a = rng.range(0..=oldest - 1);
+            let b = (a + rng.range(1..=oldest - 1)) % oldest;
+            window[a] + window[b]
+        };
+        numbers.push(number);
+    }
+
+    let mut input = format!("{preamble}\n");
+    input.push_str(&lines(numbers.into_iter().map(|n| n.to_string())));
+    input
+}
+
+/// Adapters with gaps of one and three jolts. Runs of one jolt gaps multiply the number of
+/// arrangements, they get shorter once it nears what part two's `isize` can hold.
+fn day10(rng: &mut Rng, scale: usize) -> String {
+    // arrangements of a run of n one jolt gaps between three jolt gaps.
+    const ARRANGEMENTS: [u64; 5] = [1, 1, 2, 4, 7];
+    const MAX_ARRANGEMENTS: u64 = 1_000_000_000_000_000;
+
+    let mut adapters = Vec::new();
+    let mut joltage = 0;
+    let mut arrangements: u64 = 1;
+    while adapters.len() < 100 * scale {
+        let mut run = rng.range(0..=4);
+        while arrangements * ARRANGEMENTS[run] > MAX_ARRANGEMENTS {
+            run -= 1;
+        }
+        arrangements *= ARRANGEMENTS[run];
+        for _ in 0..run {
+            joltage += 1;
+            adapters.push(joltage);
+        }
+        joltage += 3;
+        adapters.push(joltage);
+    }
+    rng.shuffle(&mut adapters);
+    lines(adapters.into_iter().map(|a: u64| a.to_string()))
+}
+
+/// Whether seats in `layout` stop changing within `max_rounds` rounds. An occupied seat
+/// empties with `crowded` occupied neighbours, which are the first seats in sight if
+/// `in_sight` or else the adjacent ones.
+fn seats_settle(layout: &[Vec<u8>], in_sight: bool, crowded: usize, max_rounds: usize) -> bool {
+    let (height, width) = (layout.len() as isize, layout[0].len() as isize);
+    let seat = |layout: &[Vec<u8>], y: isize, x: isize| {
+        (0..height).contains(&y)
+            && (0..width).contains(&x)
+            && layout[y as usize][x as usize] != b'.'
+    };
+    let mut layout = layout.to_vec();
+    for _ in 0..max_rounds {
+        let mut next = layout.clone();
+        for y in 0..height {
+            for x in 0..width {
+                if !seat(&layout, y, x) {
+                    continue;
+                }
+                let mut occupied = 0;
+                for (dy, dx) in (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dy, dx))) {
+                    if (dy, dx) == (0, 0) {
+                        continue;
+                    }
+                    let (mut ny, mut nx) = (y + dy, x + dx);
+                    while in_sight
+                        && (0..height).contains(&ny)
+                        && (0..width).contains(&nx)
+                        && !seat(&layout, ny, nx)
+                    {
+                        (ny, nx) = (ny + dy, nx + dx);
+                    }
+                    if seat(&layout, ny, nx) && layout[ny as usize][nx as usize] == b'#' {
+                        occupied += 1;
+                    }
+                }
+                let current = layout[y as usize][x as usize];
+                next[y as usize][x as usize] = match (current, occupied) {
+                    (b'L', 0) => b'#',
+                    (b'#', n) if n >= crowded => b'L',
+                    _ => current,
+                };
+            }
+        }
+        if next == layout {
+            return true;
+        }
+        layout = next;
+    }
+    false
+}
+
+/// Seat layout. Some layouts end up flipping seats back and forth forever, which the real
+/// puzzle never does, those are drawn again.
+fn day11(rng: &mut Rng, scale: usize) -> String {
+    const MAX_ROUNDS: usize = 500;
+
+    let (width, height) = (scaled_side(95, scale), scaled_side(90, scale));
+    loop {
+        let layout: Vec<Vec<u8>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if rng.chance(0.75) { b'L' } else { b'.' })
+                    .collect()
+            })
+            .collect();
+        if seats_settle(&layout, false, 4, MAX_ROUNDS) && seats_settle(&layout, true, 5, MAX_ROUNDS)
+        {
+            return lines(
+                layout
+                    .into_iter()
+                    .map(|row| String::from_utf8(row).unwrap()),
+            );
+        }
+    }
+}
+
+/// Navigation instructions, turns are multiples of 90 degrees.
+fn day12(rng: &mut Rng, scale: usize) -> String {
+    lines((0..780 * scale).map(|_| match rng.range(0..=9) {
+        0..=1 => format!("{}{}", rng.choose(&["L", "R"]), rng.choose(&[90, 180, 270])),
+        2..=4 => format!("F{}", rng.range(1..=100)),
+        _ => format!("{}{}", rng.choose(&["N", "E", "S", "W"]), rng.range(1..=5)),
+    }))
+}
+
+fn primes(max: usize) -> Vec<usize> {
+    let mut is_prime = vec![true; max + 1];
+    let mut primes = Vec::new();
+    for n in 2..=max {
+        if is_prime[n] {
+            primes.push(n);
+            (n * n..=max).step_by(n).for_each(|m| is_prime[m] = false);
+        }
+    }
+    primes
+}
+
+/// Bus schedule of distinct prime ids. Their product bounds the answer of part two, so the
+/// schedule does not grow with the scale.
+fn day13(rng: &mut Rng, _scale: usize) -> String {
+    const MAX_PRODUCT: usize = 1 << 60;
+
+    let primes = primes(1000);
+    let small: Vec<usize> = primes
+        .iter()
+        .copied()
+        .filter(|p| (13..=41).contains(p))
+        .collect();
+    let large: Vec<usize> = primes.iter().copied().filter(|&p| p >= 400).collect();
+    let ids = loop {
+        let mut ids = small.clone();
+        rng.shuffle(&mut ids);
+        ids.truncate(rng.range(5..=7));
+        for _ in 0..2 {
+            let id = *rng.choose(&large);
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        let product = ids
+            .iter()
+            .try_fold(1usize, |product, id| product.checked_mul(*id));
+        if product.is_some_and(|product| product < MAX_PRODUCT) {
+            break ids;
+        }
+    };
+
+    let length = rng.range(60..=90);
+    let mut positions: Vec<usize> = (1..length).collect();
+    rng.shuffle(&mut positions);
+    let mut schedule = vec!["x".to_string(); length];
+    schedule[0] = ids[0].to_string();
+    for (position, id) in positions.into_iter().zip(&ids[1..]) {
+        schedule[position] = id.to_string();
+    }
+    format!(
+        "{}\n{}\n",
+        rng.range(1_000_000..=1_010_000),
+        schedule.join(",")
+    )
+}
+
+/// Docking program, masks have at most nine floating bits to keep part two's writes small.
+fn day14(rng: &mut Rng, scale: usize) -> String {
+    let mut program = Vec::new();
+    for _ in 0..100 * scale {
+        let mut mask: Vec<char> = (0..36)
+            .map(|_| if rng.chance(0.5) { '1' } else { '0' })
+            .collect();
+        for _ in 0..rng.range(3..=9) {
+            let bit = rng.range(0..=35);
+            mask[bit] = 'X';
+        }
+        program.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+        for _ in 0..rng.range(1..=6) {
+            program.push(format!(
+                "mem[{}] = {}",
+                rng.range(1..=65535),
+                rng.range(1..=1 << 30)
+            ));
+        }
+    }
+    lines(program)
+}
+
+/// Distinct starting numbers. Part two always plays 30 million turns, the scale is ignored.
+fn day15(rng: &mut Rng, _scale: usize) -> String {
+    let mut numbers: Vec<usize> = (0..20).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(rng.range(6..=7));
+    let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    numbers.join(",") + "\n"
+}
+
+const TICKET_FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// Ticket rules and tickets. Every rule leaves out a small gap of values, the gaps do not
+/// overlap. The column of the n-th field to be deduced has values in the gaps of the fields
+/// deduced after it, so the fields can be told apart by elimination, one at a time.
+fn day16(rng: &mut Rng, scale: usize) -> String {
+    let fields = TICKET_FIELDS.len();
+    let mut order: Vec<&str> = TICKET_FIELDS.to_vec();
+    rng.shuffle(&mut order);
+    let mut columns: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut columns);
+
+    let slot = (940 - 60) / fields;
+    let mut slots: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut slots);
+    let gaps: Vec<(usize, usize)> = slots
+        .iter()
+        .map(|s| {
+            let start = 60 + s * slot + rng.range(2..=20);
+            (start, start + rng.range(2..=14))
+        })
+        .collect();
+    let in_gap = |value: usize, fields: &[(usize, usize)]| {
+        fields
+            .iter()
+            .any(|&(start, end)| (start..=end).contains(&value))
+    };
+    // a value every rule accepts.
+    let valid = |rng: &mut Rng| loop {
+        let value = rng.range(50..=950);
+        if !in_gap(value, &gaps) {
+            break value;
+        }
+    };
+
+    let rules: Vec<String> = order
+        .iter()
+        .zip(&gaps)
+        .map(|(field, (start, end))| {
+            let (low, high) = (rng.range(25..=50), rng.range(950..=974));
+            format!("{field}: {low}-{} or {}-{high}", start - 1, end + 1)
+        })
+        .collect();
+
+    let mut tickets: Vec<Vec<usize>> = (0..240 * scale)
+        .map(|_| {
+            let mut ticket = vec![0; fields];
+            for (field, &column) in columns.iter().enumerate() {
+                ticket[column] = if field + 1 < fields && rng.chance(0.1) {
+                    let (start, end) = gaps[rng.range(field + 1..=fields - 1)];
+                    rng.range(start..=end)
+                } else {
+                    valid(rng)
+                };
+            }
+            ticket
+        })
+        .collect();
+    // make sure every later field is ruled out by at least one value.
+    for (field, &column) in columns.iter().enumerate() {
+        for (later, &(start, end)) in gaps.iter().enumerate().skip(field + 1) {
+            tickets[later][column] = rng.range(start..=end);
+        }
+    }
+    for ticket in tickets.iter_mut().skip(fields) {
+        if rng.chance(0.25) {
+            let column = rng.range(0..=fields - 1);
+            ticket[column] = if rng.chance(0.5) {
+                rng.range(0..=24)
+            } else {
+                rng.range(975..=999)
+            };
+        }
+    }
+    rng.shuffle(&mut tickets);
+
+    let format_ticket = |ticket: &[usize]| {
+        let values: Vec<String> = ticket.iter().map(|v| v.to_string()).collect();
+        values.join(",")
+    };
+    let mine: Vec<usize> = (0..fields).map(|_| valid(rng)).collect();
+    format!(
+        "{}\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        lines(rules),
+        format_ticket(&mine),
+        lines(tickets.iter().map(|ticket| format_ticket(ticket)))
+    )
+}
+
+/// Initial slice of the pocket dimension.
+fn day17(rng: &mut Rng, scale: usize) -> String {
+    let side = scaled_side(8, scale);
+    grid(rng, side, side, b'#', 0.45)
+}
+
+/// An expression, its value when evaluated left to right and when addition goes first.
+/// `None` if either overflows.
+fn expression(rng: &mut Rng, depth: usize) -> Option<(String, u64, u64)> {
+    let operands = rng.range(2..=if depth == 0 { 6 } else { 4 });
+    let mut text = String::new();
+    let (mut left_to_right, mut product, mut sum) = (0u64, 1u64, 0u64);
+    for i in 0..operands {
+        let (operand, a, b) = if depth < 2 && rng.chance(0.3) {
+            let (text, a, b) = expression(rng, depth + 1)?;
+            (format!("({text})"), a, b)
+        } else {
+            let n = rng.range(2..=9) as u64;
+            (n.to_string(), n, n)
+        };
+        if i == 0 {
+            (text, left_to_right, sum) = (operand, a, b);
+        } else if rng.chance(0.5) {
+            text.push_str(&format!(" + {operand}"));
+            left_to_right = left_to_right.checked_add(a)?;
+            sum = sum.checked_add(b)?;
+        } else {
+            text.push_str(&format!(" * {operand}"));
+            left_to_right = left_to_right.checked_mul(a)?;
+            product = product.checked_mul(sum)?;
+            sum = b;
+        }
+    }
+    Some((text, left_to_right, product.checked_mul(sum)?))
+}
+
+/// Homework, expressions are kept small enough for both parts to add up without overflow.
+fn day18(rng: &mut Rng, scale: usize) -> String {
+    const MAX_VALUE: u64 = 10_000_000_000_000;
+
+    lines((0..380 * scale).map(|_| loop {
+        match expression(rng, 0) {
+            Some((text, a, b)) if a <= MAX_VALUE && b <= MAX_VALUE => break text,
+            _ => continue,
+        }
+    }))
+}
+
+/// One level of the message grammar, splitting strings one letter longer than the level below
+/// between its rules A and B. A string starting with `first` matches A if its rest matching A
+/// is `rest_a`, one starting with the other letter if that is `other_rest_a`.
+struct GrammarLevel {
+    first: char,
+    rest_a: bool,
+    other_rest_a: bool,
+}
+
+impl GrammarLevel {
+    fn is_a(&self, first: char, rest_is_a: bool) -> bool {
+        let expected = if first == self.first {
+            self.rest_a
+        } else {
+            self.other_rest_a
+        };
+        rest_is_a == expected
+    }
+}
+
+/// Message rules and messages. Rules 42 and 31 split all strings of eight letters between
+/// them, built up letter by letter, so every message is 42s followed by 31s or garbage.
+fn day19(rng: &mut Rng, scale: usize) -> String {
+    const LENGTH: usize = 8;
+    const RESERVED: [usize; 5] = [0, 8, 11, 31, 42];
+
+    let levels: Vec<GrammarLevel> = (1..LENGTH)
+        .map(|_| GrammarLevel {
+            first: *rng.choose(&['a', 'b']),
+            rest_a: rng.chance(0.5),
+            other_rest_a: rng.chance(0.5),
+        })
+        .collect();
+    let (a_rule, b_rule) = if rng.chance(0.5) { (42, 31) } else { (31, 42) };
+
+    // rule numbers for the A and B rules of every length below the full one.
+    let mut numbers: Vec<usize> = (1..=2 * LENGTH + 40)
+        .filter(|n| !RESERVED.contains(n))
+        .collect();
+    rng.shuffle(&mut numbers);
+    let mut rule_numbers: Vec<(usize, usize)> = numbers
+        .chunks(2)
+        .take(LENGTH - 1)
+        .map(|pair| (pair[0], pair[1]))
+        .collect();
+    rule_numbers.push((a_rule, b_rule));
+
+    let mut rules = vec![
+        "0: 8 11".to_string(),
+        "8: 42".to_string(),
+        "11: 42 31".to_string(),
+        format!("{}: \"a\"", rule_numbers[0].0),
+        format!("{}: \"b\"", rule_numbers[0].1),
+    ];
+    let letter_rule = |letter: char| {
+        if letter == 'a' {
+            rule_numbers[0].0
+        } else {
+            rule_numbers[0].1
+        }
+    };
+    for (n, level) in levels.iter().enumerate() {
+        let (rest_a, rest_b) = rule_numbers[n];
+        let (a, b) = rule_numbers[n + 1];
+        let other = if level.first == 'a' { 'b' } else { 'a' };
+        let pick = |want_a: bool| if want_a { rest_a } else { rest_b };
+        for (rule, is_a) in [(a, true), (b, false)] {
+            rules.push(format!(
+                "{rule}: {} {} | {} {}",
+                letter_rule(level.first),
+                pick(level.rest_a == is_a),
+                letter_rule(other),
+                pick(level.other_rest_a == is_a),
+            ));
+        }
+    }
+    rng.shuffle(&mut rules[3..]);
+
+    let chunk = |rng: &mut Rng, rule: usize| loop {
+        let chunk: Vec<char> = (0..LENGTH)
+            .map(|_| if rng.chance(0.5) { 'a' } else { 'b' })
+            .collect();
+        let mut is_a = chunk[LENGTH - 1] == 'a';
+        for (level, &first) in levels.iter().zip(chunk.iter().rev().skip(1)) {
+            is_a = level.is_a(first, is_a);
+        }
+        if (rule == a_rule) == is_a {
+            break chunk.into_iter().collect::<String>();
+        }
+    };
+    let messages = (0..400 * scale).map(|_| {
+        // valid messages are n 42s followed by fewer, but at least one, 31s.
+        let (forty_two, thirty_one) = match rng.range(0..=3) {
+            0 => (2, 1),
+            1 => {
+                let thirty_one = rng.range(1..=4);
+                (rng.range(thirty_one + 1..=thirty_one + 3), thirty_one)
+            }
+            2 => {
+                let forty_two = rng.range(0..=3);
+                (forty_two, rng.range(forty_two.max(1)..=4))
+            }
+            _ => {
+                let length = rng.range(3 * LENGTH - 4..=3 * LENGTH + 4);
+                return (0..length)
+                    .map(|_| if rng.chance(0.5) { 'a' } else { 'b' })
+                    .collect();
+            }
+        };
+        let mut message = String::new();
+        (0..forty_two).for_each(|_| message.push_str(&chunk(rng, 42)));
+        (0..thirty_one).for_each(|_| message.push_str(&chunk(rng, 31)));
+        message
+    });
+    let messages: Vec<String> = messages.collect();
+    format!("{}\n{}", lines(rules), lines(messages))
+}
+
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+fn rotate(image: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    let size = image.len();
+    (0..size)
+        .map(|row| (0..size).map(|col| image[col][size - 1 - row]).collect())
+        .collect()
+}
+
+/// Image tiles cut from one picture with sea monsters in it, then rotated, flipped and
+/// shuffled. Every tile border is unique, even when reversed, which only leaves room for
+/// 15 by 15 tiles.
+fn day20(rng: &mut Rng, scale: usize) -> String {
+    const TILE: usize = 10;
+
+    let side = scaled_side(12, scale).clamp(2, 15);
+    let size = side * (TILE - 1) + 1;
+    let pixels = 'picture: loop {
+        let mut pixels: Vec<Vec<u8>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(0.45) { b'#' } else { b'.' })
+                    .collect()
+            })
+            .collect();
+
+        // borders run along every ninth row and column, tiles share them with a neighbour.
+        let mut used = HashSet::new();
+        for line in 0..=side {
+            for tile in 0..side {
+                for horizontal in [true, false] {
+                    let at = |i: usize| {
+                        let (a, b) = (line * (TILE - 1), tile * (TILE - 1) + i);
+                        if horizontal {
+                            (a, b)
+                        } else {
+                            (b, a)
+                        }
+                    };
+                    let border = (0..100).find_map(|_| {
+                        let mut border: Vec<u8> =
+                            (0..TILE).map(|i| pixels[at(i).0][at(i).1]).collect();
+                        for pixel in &mut border[1..TILE - 1] {
+                            *pixel = if rng.chance(0.5) { b'#' } else { b'.' };
+                        }
+                        let reversed: Vec<u8> = border.iter().rev().copied().collect();
+                        let canonical = border.clone().min(reversed.clone());
+                        (border != reversed && used.insert(canonical)).then_some(border)
+                    });
+                    let Some(border) = border else {
+                        continue 'picture;
+                    };
+                    for (i, pixel) in border.into_iter().enumerate() {
+                        pixels[at(i).0][at(i).1] = pixel;
+                    }
+                }
+            }
+        }
+        break pixels;
+    };
+
+    // sea monsters go into the picture left once the borders are removed.
+    let mut pixels = pixels;
+    let picture_size = side * (TILE - 2);
+    let mut monsters: Vec<(usize, usize)> = Vec::new();
+    for _ in 0..side * side * 2 {
+        if monsters.len() == side * side / 8 {
+            break;
+        }
+        let (row, col) = (
+            rng.range(0..=picture_size - MONSTER.len()),
+            rng.range(0..=picture_size - MONSTER[0].len()),
+        );
+        let overlaps = monsters
+            .iter()
+            .any(|&(r, c)| row.abs_diff(r) < MONSTER.len() && col.abs_diff(c) < MONSTER[0].len());
+        if overlaps {
+            continue;
+        }
+        monsters.push((row, col));
+        for (dr, line) in MONSTER.iter().enumerate() {
+            for (dc, _) in line.bytes().enumerate().filter(|(_, b)| *b == b'#') {
+                let (r, c) = (row + dr, col + dc);
+                let to_pixel = |p: usize| p / (TILE - 2) * (TILE - 1) + 1 + p % (TILE - 2);
+                pixels[to_pixel(r)][to_pixel(c)] = b'#';
+            }
+        }
+    }
+
+    let mut ids: Vec<usize> = (1000..=9999).collect();
+    rng.shuffle(&mut ids);
+    let mut tiles: Vec<String> = Vec::with_capacity(side * side);
+    for row in 0..side {
+        for col in 0..side {
+            let mut tile: Vec<Vec<u8>> = pixels[row * (TILE - 1)..row * (TILE - 1) + TILE]
+                .iter()
+                .map(|line| line[col * (TILE - 1)..col * (TILE - 1) + TILE].to_vec())
+                .collect();
+            for _ in 0..rng.range(0..=3) {
+                tile = rotate(&tile);
+            }
+            if rng.chance(0.5) {
+                tile.iter_mut().for_each(|line| line.reverse());
+            }
+            let rows: Vec<String> = tile
+                .into_iter()
+                .map(|line| String::from_utf8(line).unwrap())
+                .collect();
+            tiles.push(format!("Tile {}:\n{}", ids[tiles.len()], rows.join("\n")));
+        }
+    }
+    rng.shuffle(&mut tiles);
+    tiles.join("\n\n") + "\n"
+}
+
+const ALLERGENS: [&str; 9] = [
+    "dairy",
+    "eggs",
+    "fish",
+    "nuts",
+    "peanuts",
+    "sesame",
+    "shellfish",
+    "soy",
+    "wheat",
+];
+
+/// Which ingredient contains which allergen, deduced the way the puzzle describes: intersect
+/// the foods listing an allergen, then eliminate known ingredients. `None` if that gets stuck.
+fn deduce_allergens<'a>(
+    foods: &[(Vec<&'a str>, Vec<&'a str>)],
+) -> Option<HashMap<&'a str, &'a str>> {
+    let mut candidates: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for (ingredients, allergens) in foods {
+        let ingredients: HashSet<&str> = ingredients.iter().copied().collect();
+        for allergen in allergens {
+            candidates
+                .entry(*allergen)
+                .and_modify(|set| set.retain(|i| ingredients.contains(i)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+
+    let mut known = HashMap::new();
+    while known.len() < candidates.len() {
+        let (allergen, ingredient) = candidates
+            .iter()
+            .filter(|(allergen, _)| !known.contains_key(*allergen))
+            .find_map(|(allergen, set)| {
+                let mut left = set.iter().filter(|i| !known.values().any(|k| k == *i));
+                match (left.next(), left.next()) {
+                    (Some(ingredient), None) => Some((*allergen, *ingredient)),
+                    _ => None,
+                }
+            })?;
+        known.insert(allergen, ingredient);
+    }
+    Some(known)
+}
+
+/// Foods. Every allergen is in one ingredient, foods list some of the allergens they contain.
+/// Foods are drawn until the allergens can be deduced by elimination.
+fn day21(rng: &mut Rng, scale: usize) -> String {
+    let mut allergens = ALLERGENS.to_vec();
+    rng.shuffle(&mut allergens);
+    allergens.truncate(rng.range(6..=8));
+
+    let mut ingredients = HashSet::new();
+    while ingredients.len() < 200 {
+        let length = rng.range(4..=8);
+        ingredients.insert(word(rng, length));
+    }
+    let mut ingredients: Vec<String> = ingredients.into_iter().collect();
+    ingredients.sort();
+    rng.shuffle(&mut ingredients);
+    let (dangerous, safe) = ingredients.split_at(allergens.len());
+
+    loop {
+        let foods: Vec<(Vec<&str>, Vec<&str>)> = (0..40 * scale)
+            .map(|_| {
+                let mut listed = allergens.clone();
+                rng.shuffle(&mut listed);
+                listed.truncate(rng.range(1..=3));
+                let mut contents: Vec<&str> = allergens
+                    .iter()
+                    .zip(dangerous)
+                    .filter(|(allergen, _)| listed.contains(allergen) || rng.chance(0.3))
+                    .map(|(_, ingredient)| ingredient.as_str())
+                    .collect();
+                let mut others: Vec<&str> = safe.iter().map(String::as_str).collect();
+                rng.shuffle(&mut others);
+                contents.extend(&others[..rng.range(8..=25)]);
+                rng.shuffle(&mut contents);
+                (contents, listed)
+            })
+            .collect();
+
+        let expected: HashMap<&str, &str> = allergens
+            .iter()
+            .zip(dangerous)
+            .map(|(allergen, ingredient)| (*allergen, ingredient.as_str()))
+            .collect();
+        if deduce_allergens(&foods).is_some_and(|known| known == expected) {
+            return lines(foods.into_iter().map(|(contents, listed)| {
+                format!("{} (contains {})", contents.join(" "), listed.join(", "))
+            }));
+        }
+    }
+}
+
+/// Card decks. Deals where plain combat would never end are drawn again.
+fn day22(rng: &mut Rng, scale: usize) -> String {
+    const MAX_ROUNDS: usize = 100_000;
+
+    let cards = 50 * scale;
+    loop {
+        let mut deck: Vec<usize> = (1..=cards).collect();
+        rng.shuffle(&mut deck);
+        let (one, two) = deck.split_at(cards / 2);
+
+        let mut players = [
+            one.iter().copied().collect::<VecDeque<_>>(),
+            two.iter().copied().collect(),
+        ];
+        let mut rounds = 0;
+        while rounds < MAX_ROUNDS && players.iter().all(|deck| !deck.is_empty()) {
+            let (a, b) = (
+                players[0].pop_front().unwrap(),
+                players[1].pop_front().unwrap(),
+            );
+            let winner = usize::from(b > a);
+            players[winner].extend([a.max(b), a.min(b)]);
+            rounds += 1;
+        }
+        if rounds < MAX_ROUNDS {
+            let deck = |cards: &[usize]| lines(cards.iter().map(|card| card.to_string()));
+            return format!("Player 1:\n{}\nPlayer 2:\n{}", deck(one), deck(two));
+        }
+    }
+}
+
+/// Cup labels, always the digits one to nine.
+fn day23(rng: &mut Rng, _scale: usize) -> String {
+    let mut cups: Vec<char> = ('1'..='9').collect();
+    rng.shuffle(&mut cups);
+    cups.into_iter().collect::<String>() + "\n"
+}
+
+/// Tile paths. Some paths lead back to a tile flipped before, flipping it back to white.
+fn day24(rng: &mut Rng, scale: usize) -> String {
+    const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+
+    let mut paths: Vec<Vec<&str>> = Vec::new();
+    for _ in 0..400 * scale {
+        let path = if !paths.is_empty() && rng.chance(0.15) {
+            let mut path = rng.choose(&paths).clone();
+            rng.shuffle(&mut path);
+            path
+        } else {
+            let length = rng.range(15..=25);
+            (0..length).map(|_| *rng.choose(&DIRECTIONS)).collect()
+        };
+        paths.push(path);
+    }
+    lines(paths.into_iter().map(|path| path.concat()))
+}
+
+/// Public keys of the card and door. Their loop sizes are what the solver searches for,
+/// the scale is ignored.
+fn day25(rng: &mut Rng, _scale: usize) -> String {
+    const MODULUS: u64 = 20201227;
+
+    let public_key = |loop_size: usize| {
+        let (mut key, mut base, mut exponent) = (1u64, 7u64, loop_size);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                key = key * base % MODULUS;
+            }
+            base = base * base % MODULUS;
+            exponent >>= 1;
+        }
+        key
+    };
+    let card = rng.range(100_000..=10_000_000);
+    let door = loop {
+        let door = rng.range(100_000..=10_000_000);
+        if door != card {
+            break door;
+        }
+    };
+    format!("{}\n{}\n", public_key(card), public_key(door))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        for day in 1..=25 {
+            let first = generate(2020, day, &mut Rng::new(3), 1).unwrap();
+            let second = generate(2020, day, &mut Rng::new(3), 1).unwrap();
+            assert!(!first.is_empty(), "day {day} generated nothing");
+            assert!(first.ends_with('\n') && !first.ends_with("\n\n"));
+            assert_eq!(first, second, "day {day} is not deterministic");
+        }
+        assert_eq!(generate(2021, 1, &mut Rng::new(3), 1), None);
+    }
+
+    #[test]
+    fn test_solvable() {
+        use crate::{solutions, RunConfig, Status};
+
+        // day 25 has no second puzzle.
+        const UNSOLVED: [(u8, u8); 1] = [(25, 2)];
+
+        let config = RunConfig::default();
+        for solution in solutions(2020).unwrap() {
+            let input = generate(2020, solution.day, &mut Rng::new(5), 1).unwrap();
+            let (parsed, result) = (solution.parse)(&input, &config);
+            assert_eq!(
+                result.status,
+                Status::Solved,
+                "day {}: {:?}",
+                solution.day,
+                result.error
+            );
+            let parsed = parsed.unwrap();
+
+            for (part, runner) in solution.parts() {
+                if UNSOLVED.contains(&(solution.day, part)) {
+                    continue;
+                }
+                let result = runner(&parsed, &config);
+                assert_eq!(
+                    result.status,
+                    Status::Solved,
+                    "day {} part {part}: {:?}",
+                    solution.day,
+                    result.error
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scale() {
+        let small = generate(2020, 2, &mut Rng::new(1), 1).unwrap();
+        let large = generate(2020, 2, &mut Rng::new(1), 3).unwrap();
+        assert_eq!(large.lines().count(), 3 * small.lines().count());
+    }
+
+    #[test]
+    fn test_day05_missing_seat() {
+        let input = generate(2020, 5, &mut Rng::new(9), 1).unwrap();
+        let mut ids: Vec<usize> = input
+            .lines()
+            .map(|pass| {
+                pass.bytes()
+                    .fold(0, |id, b| id * 2 + usize::from(matches!(b, b'B' | b'R')))
+            })
+            .collect();
+        ids.sort();
+        let gaps = ids.windows(2).filter(|pair| pair[1] != pair[0] + 1).count();
+        assert_eq!(gaps, 1);
+    }
+
+    #[test]
+    fn test_deduce_allergens() {
+        let foods = vec![
+            (vec!["mxmxvkd", "kfcds", "sqjhc"], vec!["dairy", "fish"]),
+            (vec!["trh", "fvjkl", "sbzzf", "mxmxvkd"], vec!["dairy"]),
+            (vec!["sqjhc", "fvjkl"], vec!["soy"]),
+            (vec!["sqjhc", "mxmxvkd", "sbzzf"], vec!["fish"]),
+        ];
+        let known = deduce_allergens(&foods).unwrap();
+        assert_eq!(known["dairy"], "mxmxvkd");
+        assert_eq!(known["fish"], "sqjhc");
+        assert_eq!(known["soy"], "fvjkl");
+        assert_eq!(deduce_allergens(&foods[..1]), None);
+    }
+}
@@ -0,0 +1,246 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{Parsed, PartResult, PartRunner, RegisteredDay, RunConfig, Status};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Time a part may take in `cargo all` before it is reported as timed out.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
+thread_local! {
+    static GUARDED: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Keeps panics in guarded threads off stderr, they are reported as part of the result instead.
+/// Panics anywhere else still go to the default hook.
+fn install_panic_hook() {
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if GUARDED.with(Cell::get) {
+                LAST_PANIC.with(|last| *last.borrow_mut() = Some(info.to_string()));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    // the hook's message includes the location, the payload is only a fallback.
+    if let Some(message) = LAST_PANIC.with(|last| last.borrow_mut().take()) {
+        return message.replace('\n', " ");
+    }
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "panicked".into(),
+        },
+    }
+}
+
+/// Runs `f` on the current thread, returning the panic message instead of unwinding.
+/// Like in [`run_guarded`], the panic is kept off stderr.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_panic_hook();
+    let was_guarded = GUARDED.with(|guarded| guarded.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f)).map_err(panic_message);
+    GUARDED.with(|guarded| guarded.set(was_guarded));
+    result
+}
+
+/// Why a guarded job has no result: it panicked or timed out.
+#[derive(Debug)]
+pub struct Failure {
+    pub status: Status,
+    pub message: String,
+    pub elapsed: Duration,
+}
+
+impl From<Failure> for PartResult {
+    fn from(failure: Failure) -> Self {
+        PartResult {
+            elapsed: failure.elapsed,
+            ..PartResult::failed(failure.status, failure.message)
+        }
+    }
+}
+
+/// Runs `job` on its own thread, turning a panic into [`Status::Panicked`] and a job that
+/// does not finish within `timeout` into [`Status::TimedOut`].
+/// Threads cannot be killed, a timed out job keeps running in the background until the process exits.
+pub fn run_guarded<T: Send + 'static>(
+    job: impl FnOnce() -> T + Send + 'static,
+    timeout: Option<Duration>,
+) -> Result<T, Failure> {
+    let (sender, receiver) = mpsc::channel();
+    let timer = Instant::now();
+    let spawned = thread::Builder::new().spawn(move || {
+        let result = catch_panic(job);
+        // the receiver is gone if the job timed out.
+        let _ = sender.send(result);
+    });
+
+    if let Err(e) = spawned {
+        return Err(Failure {
+            status: Status::Panicked,
+            message: format!("could not spawn thread: {e}"),
+            elapsed: Duration::ZERO,
+        });
+    }
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    match received {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(message)) => Err(Failure {
+            status: Status::Panicked,
+            message,
+            elapsed: timer.elapsed(),
+        }),
+        Err(RecvTimeoutError::Timeout) => Err(Failure {
+            status: Status::TimedOut,
+            message: format!("no answer after {:.2?}", timer.elapsed()),
+            elapsed: timer.elapsed(),
+        }),
+        Err(RecvTimeoutError::Disconnected) => Err(Failure {
+            status: Status::Panicked,
+            message: "thread exited without a result".into(),
+            elapsed: Duration::ZERO,
+        }),
+    }
+}
+
+/// Runs a day's parse step like [`run_guarded`]. If it fails, its error is what both parts report.
+pub fn parse_guarded(
+    solution: &RegisteredDay,
+    input: Arc<str>,
+    config: Arc<RunConfig>,
+    timeout: Option<Duration>,
+) -> (Result<Parsed, String>, PartResult) {
+    let parse = solution.parse;
+    match run_guarded(move || parse(&input, &config), timeout) {
+        Ok((parsed, result)) => (parsed.map_err(|e| e.to_string()), result),
+        Err(failure) => (Err(failure.message.clone()), failure.into()),
+    }
+}
+
+/// Runs a part like [`run_guarded`], on the input its day's parse step produced.
+pub fn part_guarded(
+    runner: PartRunner,
+    parsed: &Result<Parsed, String>,
+    config: Arc<RunConfig>,
+    timeout: Option<Duration>,
+) -> PartResult {
+    match parsed {
+        Ok(parsed) => {
+            let parsed = parsed.clone();
+            run_guarded(move || runner(&parsed, &config), timeout).unwrap_or_else(PartResult::from)
+        }
+        Err(e) => PartResult::failed(Status::Error, e.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guarded(runner: PartRunner, timeout: Option<Duration>) -> PartResult {
+        part_guarded(runner, &Ok(Arc::new("abc")), Arc::default(), timeout)
+    }
+
+    #[test]
+    fn test_passes_results_through() {
+        let result = guarded(
+            |parsed, config| {
+                let input = parsed.downcast_ref::<&str>().unwrap();
+                crate::run_part(|i: &&str| Some(i.len()), input, config)
+            },
+            None,
+        );
+        assert_eq!(result.status, Status::Solved);
+        assert_eq!(result.answer, Some("3".into()));
+
+        let result = part_guarded(
+            result_runner,
+            &Err("bad input".into()),
+            Arc::default(),
+            None,
+        );
+        assert_eq!(result.status, Status::Error);
+        assert_eq!(result.error, Some("bad input".into()));
+    }
+
+    fn result_runner(_: &Parsed, _: &RunConfig) -> PartResult {
+        unreachable!("not run without a parsed input")
+    }
+
+    #[test]
+    fn test_parse_guarded() {
+        let day = RegisteredDay {
+            year: 2020,
+            day: 20,
+            parses: true,
+            parse: |_, _| panic!("no tiles"),
+            part_one: result_runner,
+            part_two: result_runner,
+        };
+        let (parsed, result) = parse_guarded(&day, "abc".into(), Arc::default(), None);
+        assert_eq!(result.status, Status::Panicked);
+
+        let part = part_guarded(day.part_one, &parsed, Arc::default(), None);
+        assert_eq!(part.status, Status::Error);
+        assert!(part.error.unwrap().contains("no tiles"));
+    }
+
+    #[test]
+    fn test_catches_panics() {
+        let result = guarded(|_, _| panic!("There shouldn't be any draws"), None);
+        assert_eq!(result.status, Status::Panicked);
+        assert_eq!(result.answer, None);
+
+        let error = result.error.unwrap();
+        assert!(error.contains("There shouldn't be any draws"), "{error}");
+        assert!(error.contains("src/guard.rs"), "{error}");
+    }
+
+    #[test]
+    fn test_times_out() {
+        let result = guarded(
+            |_, _| {
+                thread::sleep(Duration::from_secs(1));
+                unreachable!()
+            },
+            Some(Duration::from_millis(20)),
+        );
+        assert_eq!(result.status, Status::TimedOut);
+        assert!(result.elapsed >= Duration::from_millis(20));
+        assert!(result.error.unwrap().starts_with("no answer after"));
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 1), Ok(1));
+        let error = catch_panic(|| panic!("out of cups")).unwrap_err();
+        assert!(error.contains("out of cups"), "{error}");
+        assert!(!GUARDED.with(Cell::get));
+    }
+}
@@ -0,0 +1,178 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Overrides the directory real inputs are read from. Inputs are still scoped by year within it.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Where `cargo solve` reads its input from, set with `--input <path>`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/inputs/YYYY/NN.txt`, or `$AOC_INPUTS_DIR/YYYY/NN.txt` if set.
+    #[default]
+    Default,
+    Path(PathBuf),
+    /// `--input -`
+    Stdin,
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err("input path must not be empty".into()),
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(path.into())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    /// `None` when reading from stdin.
+    pub path: Option<PathBuf>,
+    pub reason: io::Error,
+}
+
+impl InputError {
+    /// Whether there is no input, or only an empty one.
+    pub fn is_missing(&self) -> bool {
+        self.reason.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "could not read \"{}\": {}", path.display(), self.reason),
+            None => write!(f, "could not read input from stdin: {}", self.reason),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Directory of a data folder (`inputs`, `examples`, `puzzles`, ...) for `year`.
+/// Resolved against the crate root rather than the working directory.
+pub fn data_dir(folder: &str, year: u16) -> PathBuf {
+    let root = match env::var_os(INPUTS_DIR_VAR) {
+        Some(dir) if folder == "inputs" => dir.into(),
+        _ => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src")
+            .join(folder),
+    };
+    root.join(year.to_string())
+}
+
+pub fn input_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    data_dir(folder, year).join(format!("{day:02}.txt"))
+}
+
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    let path = input_path(folder, year, day);
+    fs::read_to_string(&path).map_err(|reason| InputError {
+        path: Some(path),
+        reason,
+    })
+}
+
+/// `cargo scaffold` leaves an empty input file behind, which counts as no input at all.
+pub fn is_blank(input: &str) -> bool {
+    input.trim().is_empty()
+}
+
+/// Whether there is an input at `path` that is not blank.
+pub fn has_input(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|input| !is_blank(&input))
+}
+
+/// Reads the input from `source`. A blank input is reported as missing.
+pub fn load_input(year: u16, day: u8, source: &InputSource) -> Result<String, InputError> {
+    let (input, path) = match source {
+        InputSource::Default => (
+            try_read_file("inputs", year, day)?,
+            Some(input_path("inputs", year, day)),
+        ),
+        InputSource::Path(path) => (
+            fs::read_to_string(path).map_err(|reason| InputError {
+                path: Some(path.clone()),
+                reason,
+            })?,
+            Some(path.clone()),
+        ),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|reason| InputError { path: None, reason })?;
+            (input, None)
+        }
+    };
+
+    if is_blank(&input) {
+        let reason = io::Error::new(io::ErrorKind::NotFound, "the input is empty");
+        return Err(InputError { path, reason });
+    }
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source_from_str() {
+        assert_eq!("-".parse(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "inputs/alt.txt".parse(),
+            Ok(InputSource::Path("inputs/alt.txt".into()))
+        );
+        assert!("".parse::<InputSource>().is_err());
+    }
+
+    #[test]
+    fn test_load_input_reports_path() {
+        let source = InputSource::Path("does/not/exist.txt".into());
+        let error = load_input(2020, 1, &source).unwrap_err();
+
+        assert!(error.is_missing());
+        assert!(error
+            .to_string()
+            .starts_with("could not read \"does/not/exist.txt\": "));
+    }
+
+    #[test]
+    fn test_blank_input_is_missing() {
+        let path = env::temp_dir().join(format!("aoc-input-blank-{}.txt", std::process::id()));
+        fs::write(&path, "\n").unwrap();
+        assert!(!has_input(&path));
+
+        let error = load_input(2020, 1, &InputSource::Path(path.clone())).unwrap_err();
+        assert!(error.is_missing());
+        assert!(error.to_string().ends_with(": the input is empty"));
+
+        fs::write(&path, "1\n").unwrap();
+        assert!(has_input(&path));
+        assert_eq!(
+            load_input(2020, 1, &InputSource::Path(path.clone())).unwrap(),
+            "1\n"
+        );
+        fs::remove_file(path).unwrap();
+        assert!(!has_input(Path::new("does/not/exist.txt")));
+    }
+
+    #[test]
+    fn test_examples_resolve_from_crate_root() {
+        assert!(try_read_file("examples", 2020, 1).is_ok());
+    }
+}
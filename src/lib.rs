@@ -3,232 +3,691 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
+use bench::BenchStats;
+use error::Answer;
+use memory::AllocStats;
+use std::any::Any;
 use std::env;
-use std::fs;
-
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// solutions refer to the library as `advent_of_code`, also from inside of it.
+extern crate self as advent_of_code;
+
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod examples;
+pub mod generate;
+pub mod guard;
 pub mod helpers;
+pub mod input;
+pub mod memory;
+pub mod output;
+pub mod perf;
+pub mod property;
+pub mod provider;
+pub mod puzzle;
+pub mod readme;
+pub mod rng;
+pub mod template;
+pub mod year2020;
+
+pub use error::SolveError;
+pub use input::{input_path, load_input, try_read_file, InputSource};
+pub use output::OutputFormat;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+/// A puzzle solution that can be linked into the `all` runner.
+/// Implemented for each day by the [`solution!`] macro, which accepts parts returning either
+/// `Option<T>` or `Result<T, SolveError>`.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// Whether the day has its own parse step, which is then timed separately from the parts.
+    const PARSES: bool;
+    /// Shared between the threads both parts run on in `cargo all`.
+    type Input: Send + Sync + 'static;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    /// Turns the raw input into what both parts work on. Days without a parse step get a copy
+    /// of the raw input.
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne, SolveError>;
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo, SolveError>;
+}
+
+/// Declares a day. `solution!(<year>, <day>, <part one type>, <part two type>)` for parts that
+/// take the raw input, or `solution!(<year>, <day>, <input type> => <part one type>, <part two type>)`
+/// for days with a `parse` function whose output is shared by both parts.
 #[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
+macro_rules! solution {
+    ($year:expr, $day:expr, $input:ty => $part_one:ty, $part_two:ty) => {
+        pub struct Day;
+
+        impl advent_of_code::Solution for Day {
+            const YEAR: u16 = $year;
+            const DAY: u8 = $day;
+            const PARSES: bool = true;
+            type Input = $input;
+            type PartOne = $part_one;
+            type PartTwo = $part_two;
+
+            fn parse(input: &str) -> Result<$input, advent_of_code::SolveError> {
+                advent_of_code::error::Answer::into_result(parse(input))
+            }
+
+            fn part_one(input: &$input) -> Result<$part_one, advent_of_code::SolveError> {
+                advent_of_code::error::Answer::into_result(part_one(input))
+            }
+
+            fn part_two(input: &$input) -> Result<$part_two, advent_of_code::SolveError> {
+                advent_of_code::error::Answer::into_result(part_two(input))
             }
         }
+    };
+    ($year:expr, $day:expr, $part_one:ty, $part_two:ty) => {
+        pub struct Day;
+
+        impl advent_of_code::Solution for Day {
+            const YEAR: u16 = $year;
+            const DAY: u8 = $day;
+            const PARSES: bool = false;
+            type Input = String;
+            type PartOne = $part_one;
+            type PartTwo = $part_two;
+
+            fn parse(input: &str) -> Result<String, advent_of_code::SolveError> {
+                Ok(input.to_string())
+            }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+            fn part_one(input: &String) -> Result<$part_one, advent_of_code::SolveError> {
+                advent_of_code::error::Answer::into_result(part_one(input))
+            }
+
+            fn part_two(input: &String) -> Result<$part_two, advent_of_code::SolveError> {
+                advent_of_code::error::Answer::into_result(part_two(input))
+            }
+        }
+    };
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Part number the parse step is reported as.
+pub const PARSE_STEP: u8 = 0;
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+/// `parse` for the parse step, `part <n>` for the parts.
+pub fn part_label(part: u8) -> String {
+    match part {
+        PARSE_STEP => "parse".into(),
+        part => format!("part {part}"),
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    MissingInput,
+    /// The part returned a [`SolveError`] other than [`SolveError::Unsolved`].
+    Error,
+    Panicked,
+    TimedOut,
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::MissingInput => "missing_input",
+            Status::Error => "error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+
+    /// Inverse of [`Status::as_str`].
+    pub fn parse(s: &str) -> Option<Self> {
+        [
+            Status::Solved,
+            Status::Unsolved,
+            Status::MissingInput,
+            Status::Error,
+            Status::Panicked,
+            Status::TimedOut,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == s)
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+pub struct PartResult {
+    pub answer: Option<String>,
+    /// Single-run time, or the median when benchmarking.
+    pub elapsed: Duration,
+    pub status: Status,
+    pub stats: Option<BenchStats>,
+    /// Heap usage of a single run, only collected with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Why a part failed, panicked or timed out.
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    pub fn missing_input() -> Self {
+        Self {
+            answer: None,
+            elapsed: Duration::ZERO,
+            status: Status::MissingInput,
+            stats: None,
+            alloc: None,
+            error: None,
         }
-    })
+    }
+
+    pub fn failed(status: Status, error: String) -> Self {
+        Self {
+            status,
+            error: Some(error),
+            ..Self::missing_input()
+        }
+    }
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Times `func` against `input`, or benchmarks it if `config.bench` is set.
+/// The returned result carries the timings, but no answer yet.
+fn timed<I: ?Sized, R>(func: impl Fn(&I) -> R, input: &I, config: &RunConfig) -> (R, PartResult) {
+    let (result, elapsed, stats, alloc) = match config.bench {
+        Some(budget) => {
+            let alloc = memory::ENABLED.then(|| memory::measure(|| func(input)).1);
+            let (result, stats) = bench::bench(func, input, budget);
+            (result, stats.median, Some(stats), alloc)
+        }
+        None => {
+            let ((result, elapsed), alloc) = memory::measure(|| {
+                let timer = Instant::now();
+                let result = func(input);
+                (result, timer.elapsed())
+            });
+            (result, elapsed, None, memory::ENABLED.then_some(alloc))
+        }
+    };
+
+    let timing = PartResult {
+        answer: None,
+        elapsed,
+        status: Status::Solved,
+        stats,
+        alloc,
+        error: None,
+    };
+    (result, timing)
 }
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
+/// Times `func` against `input`, or benchmarks it if `config.bench` is set.
+/// Only the call itself is measured, formatting the answer is not.
+/// With the `alloc-stats` feature, heap usage is measured on one additional run when benchmarking.
+pub fn run_part<I: ?Sized, T: Display, A: Answer<T>>(
+    func: impl Fn(&I) -> A,
+    input: &I,
+    config: &RunConfig,
+) -> PartResult {
+    let (result, timing) = timed(func, input, config);
+    match result.into_result() {
+        Ok(answer) => PartResult {
+            answer: Some(answer.to_string()),
+            ..timing
+        },
+        Err(SolveError::Unsolved) => PartResult {
+            status: Status::Unsolved,
+            ..timing
+        },
+        Err(e) => PartResult {
+            status: Status::Error,
+            error: Some(e.to_string()),
+            ..timing
+        },
+    }
+}
+
+/// Times a parse step like [`run_part`]. The result has no answer and is [`Status::Solved`]
+/// if parsing succeeded.
+pub fn run_parse<I, A: Answer<I>>(
+    func: impl Fn(&str) -> A,
+    input: &str,
+    config: &RunConfig,
+) -> (Result<I, SolveError>, PartResult) {
+    let (result, timing) = timed(func, input, config);
+    let parsed = result.into_result().map_err(|e| match e {
+        SolveError::Unsolved => SolveError::invalid("could not be parsed"),
+        e => e,
+    });
+
+    let timing = match &parsed {
+        Ok(_) => timing,
+        Err(e) => PartResult {
+            status: Status::Error,
+            error: Some(e.to_string()),
+            ..timing
+        },
     };
+    (parsed, timing)
+}
 
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
-    }
-
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
-                }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
-            }
+/// A day's input after its parse step, shared by both parts. Type-erased like [`RegisteredDay`].
+pub type Parsed = Arc<dyn Any + Send + Sync>;
+
+pub type ParseRunner = fn(&str, &RunConfig) -> (Result<Parsed, SolveError>, PartResult);
+pub type PartRunner = fn(&Parsed, &RunConfig) -> PartResult;
+
+/// Type-erased entry for a [`Solution`], so days with different answer types share one registry.
+#[derive(Clone, Copy)]
+pub struct RegisteredDay {
+    pub year: u16,
+    pub day: u8,
+    /// Whether the day has a parse step of its own, only then is it timed and reported.
+    pub parses: bool,
+    /// Turns the raw input into what both parts work on, once per day.
+    pub parse: ParseRunner,
+    pub part_one: PartRunner,
+    pub part_two: PartRunner,
+}
+
+impl RegisteredDay {
+    pub const fn new<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            parses: S::PARSES,
+            parse: if S::PARSES {
+                run_parse_step::<S>
+            } else {
+                raw_input::<S>
+            },
+            part_one: run_part_one::<S>,
+            part_two: run_part_two::<S>,
         }
     }
 
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
+    pub fn parts(&self) -> [(u8, PartRunner); 2] {
+        [(1, self.part_one), (2, self.part_two)]
     }
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+    /// The parts selected by `--part`.
+    pub fn selected_parts(&self, config: &RunConfig) -> impl Iterator<Item = (u8, PartRunner)> {
+        let selected = config.part;
+        self.parts()
+            .into_iter()
+            .filter(move |(part, _)| selected.is_none_or(|selected| selected == *part))
     }
+}
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
+fn run_parse_step<S: Solution>(
+    input: &str,
+    config: &RunConfig,
+) -> (Result<Parsed, SolveError>, PartResult) {
+    let (parsed, result) = run_parse(S::parse, input, config);
+    (parsed.map(|parsed| Arc::new(parsed) as Parsed), result)
+}
 
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
+// days without a parse step get a copy of the raw input, which is not worth timing.
+fn raw_input<S: Solution>(input: &str, _: &RunConfig) -> (Result<Parsed, SolveError>, PartResult) {
+    let parsed = S::parse(input).map(|parsed| Arc::new(parsed) as Parsed);
+    let result = PartResult {
+        status: Status::Solved,
+        ..PartResult::missing_input()
+    };
+    (parsed, result)
+}
 
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
+fn wrong_input<S: Solution>() -> PartResult {
+    PartResult::failed(
+        Status::Error,
+        format!("input was not parsed by day {}", S::DAY),
+    )
+}
 
-        let output = call_aoc_cli(&args)?;
+fn run_part_one<S: Solution>(parsed: &Parsed, config: &RunConfig) -> PartResult {
+    match parsed.downcast_ref() {
+        Some(input) => run_part(S::part_one, input, config),
+        None => wrong_input::<S>(),
+    }
+}
+
+fn run_part_two<S: Solution>(parsed: &Parsed, config: &RunConfig) -> PartResult {
+    match parsed.downcast_ref() {
+        Some(input) => run_part(S::part_two, input, config),
+        None => wrong_input::<S>(),
+    }
+}
 
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
+const SOLUTIONS_2020: [RegisteredDay; 25] = [
+    RegisteredDay::new::<year2020::day01::Day>(),
+    RegisteredDay::new::<year2020::day02::Day>(),
+    RegisteredDay::new::<year2020::day03::Day>(),
+    RegisteredDay::new::<year2020::day04::Day>(),
+    RegisteredDay::new::<year2020::day05::Day>(),
+    RegisteredDay::new::<year2020::day06::Day>(),
+    RegisteredDay::new::<year2020::day07::Day>(),
+    RegisteredDay::new::<year2020::day08::Day>(),
+    RegisteredDay::new::<year2020::day09::Day>(),
+    RegisteredDay::new::<year2020::day10::Day>(),
+    RegisteredDay::new::<year2020::day11::Day>(),
+    RegisteredDay::new::<year2020::day12::Day>(),
+    RegisteredDay::new::<year2020::day13::Day>(),
+    RegisteredDay::new::<year2020::day14::Day>(),
+    RegisteredDay::new::<year2020::day15::Day>(),
+    RegisteredDay::new::<year2020::day16::Day>(),
+    RegisteredDay::new::<year2020::day17::Day>(),
+    RegisteredDay::new::<year2020::day18::Day>(),
+    RegisteredDay::new::<year2020::day19::Day>(),
+    RegisteredDay::new::<year2020::day20::Day>(),
+    RegisteredDay::new::<year2020::day21::Day>(),
+    RegisteredDay::new::<year2020::day22::Day>(),
+    RegisteredDay::new::<year2020::day23::Day>(),
+    RegisteredDay::new::<year2020::day24::Day>(),
+    RegisteredDay::new::<year2020::day25::Day>(),
+];
+
+/// The days `cargo all` runs for `year`, `None` if the year has no registered days.
+pub fn solutions(year: u16) -> Option<&'static [RegisteredDay]> {
+    match year {
+        2020 => Some(&SOLUTIONS_2020),
+        _ => None,
+    }
+}
+
+/// Where a day's solution lives, e.g. `src/year2020/day08.rs`.
+/// Its binary in `src/bin` only reads the input and prints the answers.
+pub fn solution_path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join(format!("year{year}"))
+        .join(format!("day{day:02}.rs"))
+}
+
+/// Environment variable holding the year commands default to. Set in `.cargo/config`.
+pub const YEAR_VAR: &str = "AOC_YEAR";
+
+/// Reads `--year`/`-y`, falling back to `AOC_YEAR`.
+pub fn parse_year(args: &mut pico_args::Arguments) -> Result<u16, pico_args::Error> {
+    if let Some(year) = args.opt_value_from_str(["-y", "--year"])? {
+        return Ok(year);
+    }
+
+    match env::var(YEAR_VAR) {
+        Ok(value) => value.parse().map_err(|e: std::num::ParseIntError| {
+            pico_args::Error::Utf8ArgumentParsingFailed {
+                value,
+                cause: format!("invalid {YEAR_VAR}: {e}"),
+            }
+        }),
+        // the flag is known to be absent at this point, this reports it as missing.
+        Err(_) => args.value_from_str(["-y", "--year"]),
+    }
+}
+
+/// Parses a selection of days such as `7`, `10..=15`, `10..15` or a comma-separated list of
+/// those, e.g. `1,3,10..=15`. Returns the selected days in ascending order.
+pub fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day \"{day}\", expected 1 to 25")),
+    };
+
+    let mut days = Vec::new();
+    for item in s.split(',') {
+        if let Some((start, end)) = item.split_once("..=") {
+            days.extend(parse_day(start)?..=parse_day(end)?);
+        } else if let Some((start, end)) = item.split_once("..") {
+            days.extend(parse_day(start)?..parse_day(end)?);
         } else {
-            Err(AocCliError::BadExitStatus(output))
+            days.push(parse_day(item)?);
         }
     }
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
+    days.sort_unstable();
+    days.dedup();
+    if days.is_empty() {
+        return Err(format!("\"{s}\" does not select any day"));
     }
+    Ok(days)
+}
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
+fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("invalid part \"{s}\", expected 1 or 2")),
     }
+}
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
+/// Options shared by `cargo solve` and `cargo all`.
+#[derive(Default, Clone)]
+pub struct RunConfig {
+    pub format: OutputFormat,
+    /// Time budget per part when benchmarking.
+    pub bench: Option<Duration>,
+    pub input: InputSource,
+    /// Only run this part, both if `None`.
+    pub part: Option<u8>,
+}
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
+impl RunConfig {
+    /// Reads the options from the process arguments, see [`RunConfig::from_args`].
+    pub fn from_env() -> Result<Self, pico_args::Error> {
+        Self::from_args(&mut pico_args::Arguments::from_env())
+    }
 
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    /// Reads `--format <pretty|json|csv>`, falling back to the `AOC_OUTPUT` environment variable,
+    /// `--bench [--bench-time <ms>]`, `--input <path|->` and `--part <1|2>`.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let bench_time: Option<u64> = args.opt_value_from_str("--bench-time")?;
+        let bench = if args.contains("--bench") || bench_time.is_some() {
+            Some(bench_time.map_or(bench::DEFAULT_BUDGET, Duration::from_millis))
+        } else {
+            None
+        };
+
+        let format =
+            match args.opt_value_from_str("--format")? {
+                Some(format) => format,
+                None => match env::var("AOC_OUTPUT") {
+                    Ok(value) => value.parse().map_err(|cause| {
+                        pico_args::Error::Utf8ArgumentParsingFailed { value, cause }
+                    })?,
+                    Err(_) => OutputFormat::default(),
+                },
+            };
+
+        let input = args.opt_value_from_str("--input")?.unwrap_or_default();
+        let part = args.opt_value_from_fn("--part", parse_part)?;
+
+        Ok(Self {
+            format,
+            bench,
+            input,
+            part,
+        })
+    }
 
-        cmd_args
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
     }
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
+    /// Like [`RunConfig::from_env`], but exits the process on invalid arguments.
+    pub fn from_env_or_exit() -> Self {
+        Self::from_env().unwrap_or_else(|e| {
+            eprintln!("Failed to process arguments: {e}");
+            std::process::exit(1);
+        })
+    }
+}
+
+#[macro_export]
+macro_rules! solve {
+    ($part:expr, $solver:ident, $input:expr) => {{
+        use advent_of_code::Solution;
+
+        let config = advent_of_code::RunConfig::from_env_or_exit();
+        if config.runs_part($part) {
+            config.format.print_part_heading($part);
+            let result = advent_of_code::run_part($solver, $input, &config);
+            config.format.print_result(Day::DAY, $part, &result);
         }
+    }};
+}
 
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
-    }
+/// Runs a day's parse step once for both parts, reporting its time like a part's.
+/// Exits the process if the input cannot be parsed.
+#[macro_export]
+macro_rules! parse {
+    ($parser:ident, $input:expr) => {{
+        use advent_of_code::Solution;
+
+        let config = advent_of_code::RunConfig::from_env_or_exit();
+        config.format.print_part_heading(advent_of_code::PARSE_STEP);
+        let (parsed, result) = advent_of_code::run_parse($parser, $input, &config);
+        config
+            .format
+            .print_result(Day::DAY, advent_of_code::PARSE_STEP, &result);
+        match parsed {
+            Ok(parsed) => parsed,
+            Err(_) => std::process::exit(1),
+        }
+    }};
+}
+
+/// Panicking variant of [`try_read_file`], meant for tests.
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Loads the input selected by `--input` for `day`, exiting the process with a readable
+/// error instead of a panic if it cannot be read.
+pub fn read_input(year: u16, day: u8) -> String {
+    let config = RunConfig::from_env_or_exit();
+    load_input(year, day, &config.input).unwrap_or_else(|e| {
+        eprintln!("Failed to load input: {e}");
+        std::process::exit(1);
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    struct Example;
+
+    impl Solution for Example {
+        const YEAR: u16 = 2020;
+        const DAY: u8 = 7;
+        const PARSES: bool = true;
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = String;
+
+        fn parse(input: &str) -> Result<Vec<u32>, SolveError> {
+            input
+                .split(',')
+                .map(|n| error::parse_in(input, n))
+                .collect()
+        }
+
+        fn part_one(input: &Vec<u32>) -> Result<u32, SolveError> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(_input: &Vec<u32>) -> Result<String, SolveError> {
+            Err(SolveError::invalid("no part two"))
+        }
+    }
+
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
+    fn test_registered_day() {
+        let registered = RegisteredDay::new::<Example>();
+        assert_eq!((registered.year, registered.day), (2020, 7));
+        let config = RunConfig::default();
+
+        assert!(registered.parses);
+
+        let (parsed, result) = (registered.parse)("1,2", &config);
+        assert_eq!((result.answer, result.status), (None, Status::Solved));
+        let parsed = parsed.unwrap();
+        assert_eq!(
+            (registered.part_one)(&parsed, &config).answer,
+            Some("3".into())
         );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
+        let failed = (registered.part_two)(&parsed, &config);
+        assert_eq!((failed.answer, failed.status), (None, Status::Error));
+        assert_eq!(failed.error.as_deref(), Some("invalid input: no part two"));
+
+        let other: Parsed = Arc::new(String::from("1,2"));
+        let failed = (registered.part_one)(&other, &config);
+        assert_eq!(failed.status, Status::Error);
+        assert_eq!(
+            failed.error.as_deref(),
+            Some("input was not parsed by day 7")
         );
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        let (parsed, result) = (registered.parse)("1,x", &config);
+        assert_eq!(result.status, Status::Error);
+        assert_eq!(
+            result.error.as_deref(),
+            Some("parse error at line 1, column 3: invalid digit found in string (\"x\")")
         );
+        assert!(parsed.is_err());
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    #[test]
+    fn test_run_part_option() {
+        let config = RunConfig::default();
+        let unsolved = run_part(|_| None::<u8>, "", &config);
+        assert_eq!((unsolved.status, unsolved.error), (Status::Unsolved, None));
+        let solved = run_part(|input| Some(input.len()), "ab", &config);
+        assert_eq!(solved.answer.as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(vec![7]));
+        assert_eq!(parse_days("10..=12"), Ok(vec![10, 11, 12]));
+        assert_eq!(parse_days("10..12"), Ok(vec![10, 11]));
+        assert_eq!(parse_days("3,1,2..=3"), Ok(vec![1, 2, 3]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("24..=26").is_err());
+        assert!(parse_days("5..5").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_parse_part() {
+        let mut args = pico_args::Arguments::from_vec(vec!["--part".into(), "2".into()]);
+        let config = RunConfig::from_args(&mut args).unwrap();
+        assert!(!config.runs_part(1));
+        assert!(config.runs_part(2));
+        assert!(RunConfig::default().runs_part(1));
+        assert!(parse_part("3").is_err());
+    }
+
+    #[test]
+    fn test_solutions() {
+        let days: Vec<(u16, u8)> = solutions(2020)
+            .unwrap()
+            .iter()
+            .map(|solution| (solution.year, solution.day))
+            .collect();
+        assert_eq!(days, (1..=25).map(|day| (2020, day)).collect::<Vec<_>>());
+        assert!(solutions(2019).is_none());
+        assert!(solution_path(2020, 8).ends_with("src/year2020/day08.rs"));
     }
 }
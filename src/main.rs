@@ -2,44 +2,633 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{answers_path, read_answers, Verdict};
+use advent_of_code::input::{InputError, INPUTS_DIR_VAR};
+use advent_of_code::memory::{format_bytes, AllocStats};
+use advent_of_code::provider::{self, Submission};
+use advent_of_code::{guard, perf, readme};
+use advent_of_code::{
+    solutions, InputSource, PartResult, RegisteredDay, RunConfig, Status, ANSI_BOLD, ANSI_ITALIC,
+    ANSI_RESET, PARSE_STEP,
+};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+use std::{env, fs};
+use std::{process, thread};
+
+enum Mode {
+    Run,
+    Verify,
+    /// `--submit <day> <part>`
+    Submit {
+        day: u8,
+        part: u8,
+    },
+    /// `--perf compare [--baseline <rev>] [--threshold <percent>]`
+    PerfCompare {
+        baseline: Option<String>,
+        threshold: f64,
+    },
+    /// `--readme`
+    Readme,
+}
+
+struct Args {
+    year: u16,
+    mode: Mode,
+    config: RunConfig,
+    /// Days selected with e.g. `10..=15` or `1,3,5`, all if `None`.
+    days: Option<Vec<u8>>,
+    jobs: usize,
+    /// Time each part may take, `None` if disabled with `--timeout 0`.
+    timeout: Option<Duration>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = advent_of_code::parse_year(&mut args)?;
+    let config = RunConfig::from_args(&mut args)?;
+
+    let jobs = match args.opt_value_from_str(["-j", "--jobs"])? {
+        Some(jobs) => jobs,
+        None if args.contains("--parallel") => {
+            thread::available_parallelism().map_or(1, |n| n.get())
+        }
+        None => 1,
+    };
+
+    let timeout = match args.opt_value_from_str("--timeout")? {
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+        None => Some(guard::DEFAULT_TIMEOUT),
+    };
+
+    let mode = if args.contains("--submit") {
+        Mode::Submit {
+            day: args.free_from_str()?,
+            part: args.free_from_str()?,
+        }
+    } else if args.contains("--perf") {
+        let baseline = args.opt_value_from_str("--baseline")?;
+        let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+        match args.free_from_str::<String>()?.as_str() {
+            "compare" => Mode::PerfCompare {
+                baseline,
+                threshold: threshold.map_or(perf::DEFAULT_THRESHOLD, |percent| percent / 100.0),
+            },
+            command => {
+                return Err(pico_args::Error::Utf8ArgumentParsingFailed {
+                    value: command.into(),
+                    cause: "unknown perf command, expected: compare".into(),
+                })
+            }
+        }
+    } else if args.contains("--readme") {
+        Mode::Readme
+    } else if args.contains("--verify") {
+        Mode::Verify
+    } else {
+        Mode::Run
+    };
+
+    // free arguments are taken in order, so the day selection has to come last.
+    let days = match mode {
+        Mode::Submit { .. } => None,
+        _ => args.opt_free_from_fn(advent_of_code::parse_days)?,
+    };
+
+    Ok(Args {
+        year,
+        mode,
+        config,
+        days,
+        jobs,
+        timeout,
+    })
+}
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let config = &args.config;
+    if config.input != InputSource::Default {
+        eprintln!("--input can only be used when solving a single day.");
+        process::exit(1);
+    }
+
+    let Some(registered) = solutions(args.year) else {
+        eprintln!("No solutions registered for {}.", args.year);
+        process::exit(1);
+    };
+    let solutions = &select_days(registered, args.days.as_deref());
+
+    if matches!(args.mode, Mode::Readme) && (args.days.is_some() || config.part.is_some()) {
+        eprintln!("The benchmark table always covers every part of every day.");
+        process::exit(1);
+    }
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
+    match &args.mode {
+        Mode::Run => {
+            let results = run(solutions, config, args.jobs, args.timeout);
+            record_history(&args, &results);
+        }
+        Mode::Verify => verify(solutions, config, args.timeout),
+        Mode::Submit { day, part } => submit(solutions, config, *day, *part, args.timeout),
+        Mode::PerfCompare {
+            baseline,
+            threshold,
+        } => {
+            if let Some(reason) = unrecorded_reason(&args) {
+                eprintln!("Timings are not recorded {reason}.");
+                process::exit(1);
             }
+            let results = run(solutions, config, args.jobs, args.timeout);
+            let current = record_history(&args, &results).unwrap_or_default();
+            perf_compare(args.year, &current, baseline.as_deref(), *threshold);
+        }
+        Mode::Readme => {
+            let results = run(solutions, config, args.jobs, args.timeout);
+            record_history(&args, &results);
+            update_readme(solutions, &results);
+        }
+    }
+}
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+/// Registered days in `days`, exiting if one of them has no solution.
+fn select_days(solutions: &[RegisteredDay], days: Option<&[u8]>) -> Vec<RegisteredDay> {
+    let Some(days) = days else {
+        return solutions.to_vec();
+    };
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+    days.iter()
+        .map(|&day| {
+            let solution = solutions.iter().find(|solution| solution.day == day);
+            *solution.unwrap_or_else(|| {
+                eprintln!("Day {day:02} is not registered.");
+                process::exit(1);
+            })
+        })
+        .collect()
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+fn read_input(solution: &RegisteredDay) -> Result<String, InputError> {
+    advent_of_code::load_input(solution.year, solution.day, &InputSource::Default)
+}
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
+/// Runs `job` for every day on `jobs` worker threads, handing results to `emit` in day order.
+fn run_in_order<T: Send>(
+    solutions: &[RegisteredDay],
+    jobs: usize,
+    job: impl Fn(&RegisteredDay) -> T + Sync,
+    mut emit: impl FnMut(T),
+) {
+    if jobs <= 1 {
+        solutions.iter().map(&job).for_each(emit);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(solutions.len()) {
+            let sender = sender.clone();
+            let (next, job) = (&next, &job);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(solution) = solutions.get(index) else {
+                    break;
+                };
+                // the receiver outlives all workers.
+                sender.send((index, job(solution))).unwrap();
+            });
+        }
+        drop(sender);
+
+        let mut pending: Vec<Option<T>> = solutions.iter().map(|_| None).collect();
+        let mut printed = 0;
+        for (index, output) in receiver {
+            pending[index] = Some(output);
+            while let Some(output) = pending.get_mut(printed).and_then(Option::take) {
+                emit(output);
+                printed += 1;
+            }
+        }
+    });
+}
+
+/// Runs all days, printing their results in order. Returns each part's result.
+fn run(
+    solutions: &[RegisteredDay],
+    config: &RunConfig,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<(u8, u8, PartResult)> {
+    let format = config.format;
+    format.print_preamble();
+    let shared = Arc::new(config.clone());
+
+    let run_day = |solution: &RegisteredDay| {
+        let mut out = String::new();
+        let mut results = Vec::new();
+        format.write_day_heading(&mut out, solution.day);
+
+        let input = read_input(solution).map(Arc::<str>::from);
+        if let Err(e) = &input {
+            if format.is_pretty() {
+                if e.is_missing() {
+                    out.push_str("Not solved.\n");
                 } else {
-                    output.trim()
+                    let _ = writeln!(out, "Not solved: {e}");
+                }
+                return (out, results);
+            }
+        }
+
+        // parsed once, both parts share the result.
+        let parsed = input.as_ref().ok().map(|input| {
+            let (parsed, result) =
+                guard::parse_guarded(solution, input.clone(), shared.clone(), timeout);
+            if solution.parses {
+                format.write_part_heading(&mut out, PARSE_STEP);
+                format.write_result(&mut out, solution.day, PARSE_STEP, &result);
+                results.push((solution.day, PARSE_STEP, result));
+            }
+            parsed
+        });
+
+        for (part, solver) in solution.selected_parts(config) {
+            format.write_part_heading(&mut out, part);
+            let result = match (&parsed, &input) {
+                (Some(parsed), _) => guard::part_guarded(solver, parsed, shared.clone(), timeout),
+                // an input that exists but cannot be read says why, like in pretty output.
+                (None, Err(e)) if !e.is_missing() => {
+                    PartResult::failed(Status::MissingInput, e.to_string())
                 }
+                (None, _) => PartResult::missing_input(),
+            };
+            format.write_result(&mut out, solution.day, part, &result);
+            results.push((solution.day, part, result));
+        }
+
+        (out, results)
+    };
+
+    let timer = Instant::now();
+    let mut results = Vec::new();
+    run_in_order(solutions, jobs, run_day, |(out, day_results)| {
+        print!("{out}");
+        results.extend(day_results);
+    });
+    let wall_clock = timer.elapsed();
+
+    if format.is_pretty() {
+        let total: Duration = results.iter().map(|(_, _, result)| result.elapsed).sum();
+        // when benchmarking, the total is the sum of each part's median.
+        let label = if config.bench.is_some() {
+            "Total (medians):"
+        } else {
+            "Total:"
+        };
+        println!(
+            "{ANSI_BOLD}{label}{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET}",
+            total.as_secs_f64() * 1000_f64
+        );
+        println!(
+            "{ANSI_BOLD}Wall clock:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms ({jobs} {}){ANSI_RESET}",
+            wall_clock.as_secs_f64() * 1000_f64,
+            if jobs == 1 { "worker" } else { "workers" }
+        );
+
+        let allocs: Vec<_> = results
+            .iter()
+            .filter_map(|(day, part, result)| Some((*day, *part, result.alloc?)))
+            .collect();
+        print_alloc_summary(&allocs);
+    }
+
+    results
+}
+
+/// Why the timings of a run are not comparable with the history, `None` if they are.
+fn unrecorded_reason(args: &Args) -> Option<&'static str> {
+    if cfg!(debug_assertions) {
+        Some("for debug builds")
+    } else if args.jobs > 1 {
+        Some("when days run in parallel")
+    } else if env::var_os(INPUTS_DIR_VAR).is_some() {
+        Some("for inputs read from AOC_INPUTS_DIR")
+    } else if !args.config.format.is_pretty() {
+        Some("with --format json or csv")
+    } else {
+        None
+    }
+}
+
+/// Appends the timings of solved parts to the history used by `cargo perf compare`.
+/// Runs whose timings are not comparable, see [`unrecorded_reason`], are not recorded.
+fn record_history(args: &Args, results: &[(u8, u8, PartResult)]) -> Option<Vec<perf::Entry>> {
+    if unrecorded_reason(args).is_some() {
+        return None;
+    }
+
+    let path = perf::history_path(args.year);
+    // an unreadable history is reported by `cargo perf compare`, timings are still appended.
+    let run = perf::read_history(&path).map_or(1, |history| perf::next_run(&history));
+    let (rev, date) = (perf::git_rev(), perf::now_utc());
+    let entries: Vec<perf::Entry> = results
+        .iter()
+        .filter(|(_, _, result)| result.status == Status::Solved)
+        .map(|(day, part, result)| perf::Entry {
+            run,
+            rev: rev.clone(),
+            date: date.clone(),
+            day: *day,
+            part: *part,
+            duration: result.elapsed,
+        })
+        .collect();
+
+    if let Err(e) = perf::append_history(&path, &entries) {
+        eprintln!("Failed to record timings: {e}");
+    }
+    Some(entries)
+}
+
+/// Totals the heap usage collected with the `alloc-stats` feature, naming the hungriest part.
+fn print_alloc_summary(allocs: &[(u8, u8, AllocStats)]) {
+    let Some((day, part, peak)) = allocs.iter().max_by_key(|(_, _, alloc)| alloc.peak) else {
+        return;
+    };
+
+    let allocations: u64 = allocs.iter().map(|(_, _, alloc)| alloc.allocations).sum();
+    let allocated: u64 = allocs.iter().map(|(_, _, alloc)| alloc.allocated).sum();
+    println!(
+        "{ANSI_BOLD}Allocations:{ANSI_RESET} {ANSI_ITALIC}{allocations} ({} allocated){ANSI_RESET}",
+        format_bytes(allocated)
+    );
+    println!(
+        "{ANSI_BOLD}Peak memory:{ANSI_RESET} {ANSI_ITALIC}{} (day {day:02} {}){ANSI_RESET}",
+        format_bytes(peak.peak),
+        advent_of_code::part_label(*part)
+    );
+}
+
+/// Checks every day against `src/answers`, exiting with a non-zero status on any mismatch.
+fn verify(solutions: &[RegisteredDay], config: &RunConfig, timeout: Option<Duration>) {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let shared = Arc::new(config.clone());
+
+    for solution in solutions {
+        let answers = match read_answers(solution.year, solution.day) {
+            Ok(answers) => answers.unwrap_or_default(),
+            Err(e) => {
+                println!(
+                    "Day {:02}: {ANSI_BOLD}FAIL{ANSI_RESET} invalid answers file: {e}",
+                    solution.day
+                );
+                failed += solution.selected_parts(config).count();
+                continue;
+            }
+        };
+        let input = read_input(solution).map(Arc::<str>::from);
+        // only parsed if there is an answer to check, once for both parts.
+        let expects_any = solution
+            .selected_parts(config)
+            .any(|(part, _)| answers.get(part).is_some());
+        let parsed = match &input {
+            Ok(input) if expects_any => {
+                Some(guard::parse_guarded(solution, input.clone(), shared.clone(), timeout).0)
+            }
+            _ => None,
+        };
+
+        for (part, solver) in solution.selected_parts(config) {
+            let expected = answers.get(part);
+            let result = match (&parsed, expected) {
+                (Some(parsed), Some(_)) => {
+                    guard::part_guarded(solver, parsed, shared.clone(), timeout)
+                }
+                _ => PartResult::missing_input(),
+            };
+
+            let label = format!("Day {:02} part {part}:", solution.day);
+            match Verdict::check(expected, &result) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{label} {ANSI_BOLD}PASS{ANSI_RESET}");
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+                    let answer = result
+                        .answer
+                        .as_deref()
+                        .or(result.error.as_deref())
+                        .unwrap_or("not solved");
+                    println!(
+                        "{label} {ANSI_BOLD}FAIL{ANSI_RESET} expected {expected}, got {answer}"
+                    );
+                }
+                Verdict::Missing => {
+                    missing += 1;
+                    let reason = if expected.is_none() {
+                        "no recorded answer"
+                    } else {
+                        "no input"
+                    };
+                    println!("{label} MISSING {ANSI_ITALIC}({reason}){ANSI_RESET}");
+                }
+            }
+        }
+    }
+
+    println!("---");
+    println!("{passed} passed, {failed} failed, {missing} missing.");
+
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Solves one part of a day and submits the answer, unless earlier submissions already settle it.
+fn submit(
+    solutions: &[RegisteredDay],
+    config: &RunConfig,
+    day: u8,
+    part: u8,
+    timeout: Option<Duration>,
+) {
+    let Some(solution) = solutions.iter().find(|solution| solution.day == day) else {
+        eprintln!("Day {day:02} is not registered.");
+        process::exit(1);
+    };
+    let Some((_, solver)) = solution.parts().into_iter().find(|(p, _)| *p == part) else {
+        eprintln!("Part must be 1 or 2, got {part}.");
+        process::exit(1);
+    };
+
+    let input = read_input(solution).unwrap_or_else(|e| {
+        eprintln!("Failed to load input: {e}");
+        process::exit(1);
+    });
+    let shared = Arc::new(config.clone());
+    let (parsed, _) = guard::parse_guarded(solution, input.into(), shared.clone(), timeout);
+    let result = guard::part_guarded(solver, &parsed, shared, timeout);
+    let Some(answer) = result.answer else {
+        match result.error {
+            Some(error) => eprintln!("Day {day:02} part {part} is not solved: {error}"),
+            None => eprintln!("Day {day:02} part {part} is not solved."),
+        }
+        process::exit(1);
+    };
+
+    let label = format!("Day {day:02} part {part}:");
+    println!("{label} submitting {ANSI_BOLD}{answer}{ANSI_RESET}");
+
+    let path = answers_path(solution.year, day);
+    let verdict = provider::known_verdict(&path, part, &answer).and_then(|known| match known {
+        Some(verdict) => {
+            println!("{ANSI_ITALIC}already settled by an earlier submission.{ANSI_RESET}");
+            Ok(verdict)
+        }
+        None => provider::submit_and_record(
+            provider::from_env().as_ref(),
+            &path,
+            solution.year,
+            day,
+            part,
+            &answer,
+        ),
+    });
+
+    match verdict {
+        Ok(Submission::Unknown(response)) => {
+            println!("{label} {ANSI_BOLD}UNKNOWN{ANSI_RESET} {response}");
+            process::exit(1);
+        }
+        Ok(verdict) => {
+            println!(
+                "{label} {ANSI_BOLD}{}{ANSI_RESET}",
+                verdict.as_str().replace('_', " ").to_uppercase()
             );
+            if verdict != Submission::Correct {
+                process::exit(1);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to submit: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Compares the run that was just recorded against an earlier one from the history,
+/// exiting with a non-zero status if any part became slower than `threshold` allows.
+fn perf_compare(year: u16, current: &[perf::Entry], baseline: Option<&str>, threshold: f64) {
+    let history = perf::read_history(&perf::history_path(year)).unwrap_or_else(|e| {
+        eprintln!("Failed to read timing history: {e}");
+        process::exit(1);
+    });
+    let earlier = perf::earlier(&history, current);
+    match baseline {
+        Some(rev) if !earlier.iter().any(|entry| entry.rev.starts_with(rev)) => {
+            eprintln!("No run of revision \"{rev}\" in the timing history.");
+            process::exit(1);
+        }
+        None if earlier.is_empty() => {
+            println!("No earlier run to compare against, this run is the baseline.");
+            return;
+        }
+        _ => {}
+    }
+
+    println!("---");
+    match baseline {
+        Some(rev) => println!("{ANSI_BOLD}Compared to the latest timings of {rev}:{ANSI_RESET}"),
+        None => println!("{ANSI_BOLD}Compared to the latest earlier timings:{ANSI_RESET}"),
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let mut regressed = 0;
+    for comparison in perf::compare(earlier, current, baseline) {
+        let label = format!(
+            "Day {:02} {}:",
+            comparison.day,
+            advent_of_code::part_label(comparison.part)
+        );
+        match (comparison.baseline, comparison.change()) {
+            (Some(before), Some(change)) => {
+                let flag = if comparison.regressed(threshold) {
+                    regressed += 1;
+                    format!(" {ANSI_BOLD}REGRESSED{ANSI_RESET}")
+                } else {
+                    String::new()
+                };
+                println!(
+                    "{label} {before:.2?} -> {:.2?} {ANSI_ITALIC}({:+.1}%){ANSI_RESET}{flag}",
+                    comparison.current,
+                    change * 100.0
+                );
             }
+            _ => println!(
+                "{label} {:.2?} {ANSI_ITALIC}(new){ANSI_RESET}",
+                comparison.current
+            ),
+        }
+    }
+
+    println!("---");
+    println!(
+        "{regressed} {} regressed by more than {:.0}%.",
+        if regressed == 1 { "part" } else { "parts" },
+        threshold * 100.0
+    );
+
+    if regressed > 0 {
+        process::exit(1);
+    }
+}
+
+/// Rewrites the benchmark table in `README.md` with the timings of a run.
+fn update_readme(solutions: &[RegisteredDay], results: &[(u8, u8, PartResult)]) {
+    let time = |day: u8, part: u8| {
+        results
+            .iter()
+            .find(|(d, p, result)| (*d, *p) == (day, part) && result.status == Status::Solved)
+            .map(|(_, _, result)| result.elapsed)
+    };
+    let rows: Vec<readme::Row> = solutions
+        .iter()
+        .map(|solution| readme::Row {
+            year: solution.year,
+            day: solution.day,
+            parse: time(solution.day, PARSE_STEP),
+            part_one: time(solution.day, 1),
+            part_two: time(solution.day, 2),
         })
-        .sum();
+        .collect();
+
+    let path = readme::readme_path();
+    let updated = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| readme::update(&contents, &readme::render_table(&rows)))
+        .and_then(|updated| fs::write(&path, updated).map_err(|e| e.to_string()));
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    match updated {
+        Ok(()) => println!(
+            "---\n🎄 Updated the benchmark table in \"{}\".",
+            path.display()
+        ),
+        Err(e) => {
+            eprintln!("Failed to update \"{}\": {e}", path.display());
+            process::exit(1);
+        }
+    }
 }
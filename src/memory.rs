@@ -0,0 +1,163 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+/// Whether the counting allocator is installed, i.e. the `alloc-stats` feature is enabled.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// Heap usage of a single call, measured by [`CountingAllocator`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    /// Total bytes requested, including memory that was freed again.
+    pub allocated: u64,
+    /// Most bytes live at once, on top of what was live before the call.
+    pub peak: u64,
+}
+
+// counters are per thread, so days run in parallel do not skew each other's numbers.
+// a `const` initialized thread local of `Cell`s never allocates, which makes it usable here.
+struct Counters {
+    allocations: Cell<u64>,
+    allocated: Cell<u64>,
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+thread_local! {
+    static COUNTERS: Counters = const {
+        Counters {
+            allocations: Cell::new(0),
+            allocated: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // fails while the thread is being torn down, those allocations are not of interest.
+    let _ = COUNTERS.try_with(|counters| {
+        if allocated > 0 {
+            counters.allocations.set(counters.allocations.get() + 1);
+            counters
+                .allocated
+                .set(counters.allocated.get() + allocated as u64);
+        }
+        let live = counters.live.get() + allocated as i64 - freed as i64;
+        counters.live.set(live);
+        counters.peak.set(counters.peak.get().max(live));
+    });
+}
+
+/// Wraps the system allocator, counting every allocation made by the current thread.
+/// Installed as the global allocator by the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Runs `func`, returning its result and the allocations it made on the current thread.
+/// The numbers are all zero unless the `alloc-stats` feature is enabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let (allocations, allocated, live) = COUNTERS.with(|counters| {
+        counters.peak.set(counters.live.get());
+        (
+            counters.allocations.get(),
+            counters.allocated.get(),
+            counters.live.get(),
+        )
+    });
+
+    let result = func();
+
+    let stats = COUNTERS.with(|counters| AllocStats {
+        allocations: counters.allocations.get() - allocations,
+        allocated: counters.allocated.get() - allocated,
+        peak: (counters.peak.get() - live).max(0) as u64,
+    });
+
+    (result, stats)
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(30 * 1024 * 1024), "30.00 MiB");
+    }
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let mut items = Vec::with_capacity(1024);
+            items.extend(0..1024_u64);
+            drop(Vec::<u64>::with_capacity(16));
+            items.len()
+        });
+        assert_eq!(len, 1024);
+
+        if ENABLED {
+            assert_eq!(stats.allocations, 2);
+            assert_eq!(stats.allocated, 1024 * 8 + 16 * 8);
+            assert_eq!(stats.peak, 1024 * 8 + 16 * 8);
+        } else {
+            assert_eq!(stats, AllocStats::default());
+        }
+    }
+}
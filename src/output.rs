@@ -0,0 +1,462 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    memory::format_bytes, PartResult, Status, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PARSE_STEP,
+};
+use std::{
+    fmt::Write,
+    str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+/// How run results are written to stdout.
+/// `Json` emits one object per line, `Csv` emits a header followed by one row per part.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Pretty,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(OutputFormat::Pretty),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!(
+                "unknown output format \"{s}\", expected one of: pretty, json, csv"
+            )),
+        }
+    }
+}
+
+static CSV_HEADER_PRINTED: AtomicBool = AtomicBool::new(false);
+
+// the `write_*` methods buffer output so that days run in parallel can be printed in order.
+// writing to a `String` cannot fail, hence the ignored `fmt::Result`s.
+impl OutputFormat {
+    pub fn is_pretty(&self) -> bool {
+        *self == OutputFormat::Pretty
+    }
+
+    /// Emits the CSV header up front. Otherwise, it is printed lazily with the first result.
+    pub fn print_preamble(&self) {
+        if *self == OutputFormat::Csv && !CSV_HEADER_PRINTED.swap(true, Ordering::Relaxed) {
+            println!("{CSV_HEADER}");
+        }
+    }
+
+    pub fn write_day_heading(&self, out: &mut String, day: u8) {
+        if self.is_pretty() {
+            let _ = writeln!(out, "----------");
+            let _ = writeln!(out, "{ANSI_BOLD}| Day {day:02} |{ANSI_RESET}");
+            let _ = writeln!(out, "----------");
+        }
+    }
+
+    pub fn write_part_heading(&self, out: &mut String, part: u8) {
+        if self.is_pretty() {
+            if part == PARSE_STEP {
+                let _ = writeln!(out, "🎄 {ANSI_BOLD}Parse{ANSI_RESET} 🎄");
+            } else {
+                let _ = writeln!(out, "🎄 {ANSI_BOLD}Part {part}{ANSI_RESET} 🎄");
+            }
+        }
+    }
+
+    pub fn write_result(&self, out: &mut String, day: u8, part: u8, result: &PartResult) {
+        let _ = match self {
+            // a parse step that succeeded has nothing to show but its timings.
+            OutputFormat::Pretty if result.answer.is_some() || result.status == Status::Solved => {
+                let mut details = match &result.stats {
+                    Some(stats) => format!(
+                        "median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} iterations",
+                        stats.median, stats.mean, stats.stddev, stats.min, stats.iterations
+                    ),
+                    None => format!("elapsed: {:.2?}", result.elapsed),
+                };
+                if let Some(alloc) = &result.alloc {
+                    let _ = write!(
+                        details,
+                        ", {} allocations, {} allocated, {} peak",
+                        alloc.allocations,
+                        format_bytes(alloc.allocated),
+                        format_bytes(alloc.peak)
+                    );
+                }
+                match &result.answer {
+                    Some(answer) => writeln!(out, "{answer} {ANSI_ITALIC}({details}){ANSI_RESET}"),
+                    None => writeln!(out, "{ANSI_ITALIC}({details}){ANSI_RESET}"),
+                }
+            }
+            OutputFormat::Pretty => match (result.status, &result.error) {
+                (Status::Error, Some(error)) => {
+                    writeln!(out, "{ANSI_BOLD}ERROR{ANSI_RESET} {error}")
+                }
+                (Status::Panicked, Some(error)) => {
+                    writeln!(out, "{ANSI_BOLD}PANICKED{ANSI_RESET} {error}")
+                }
+                (Status::TimedOut, Some(error)) => {
+                    writeln!(out, "{ANSI_BOLD}TIMED OUT{ANSI_RESET} {error}")
+                }
+                _ => writeln!(out, "not solved."),
+            },
+            OutputFormat::Json => writeln!(out, "{}", to_json(day, part, result)),
+            OutputFormat::Csv => writeln!(out, "{}", to_csv(day, part, result)),
+        };
+    }
+
+    pub fn print_part_heading(&self, part: u8) {
+        let mut out = String::new();
+        self.write_part_heading(&mut out, part);
+        print!("{out}");
+    }
+
+    pub fn print_result(&self, day: u8, part: u8, result: &PartResult) {
+        self.print_preamble();
+        let mut out = String::new();
+        self.write_result(&mut out, day, part, result);
+        print!("{out}");
+    }
+}
+
+pub fn to_json(day: u8, part: u8, result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Some(answer) => format!("\"{}\"", escape_json(answer)),
+        None => "null".into(),
+    };
+
+    let stats = match &result.stats {
+        Some(stats) => format!(
+            ",\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}",
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ),
+        None => String::new(),
+    };
+
+    let alloc = match &result.alloc {
+        Some(alloc) => format!(
+            ",\"allocations\":{},\"allocated_bytes\":{},\"peak_bytes\":{}",
+            alloc.allocations, alloc.allocated, alloc.peak
+        ),
+        None => String::new(),
+    };
+
+    let error = match &result.error {
+        Some(error) => format!(",\"error\":\"{}\"", escape_json(error)),
+        None => String::new(),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{},\"status\":\"{}\"{}{}{}}}",
+        day,
+        part,
+        answer,
+        result.elapsed.as_nanos(),
+        result.status.as_str(),
+        stats,
+        alloc,
+        error
+    )
+}
+
+/// The fields of a record written by [`to_json`] that are needed to read a run back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub status: Status,
+}
+
+/// Parses a record written by [`to_json`], e.g. from the output of a solution binary.
+pub fn from_json(record: &str) -> Option<Record> {
+    let value = |key: &str| -> Option<&str> {
+        let key = format!("\"{key}\":");
+        Some(&record[record.find(&key)? + key.len()..])
+    };
+    let number = |key: &str| -> Option<u64> {
+        let digits: String = value(key)?
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok()
+    };
+
+    let answer = value("answer")?;
+    let answer = match answer.strip_prefix('"') {
+        Some(quoted) => Some(unescape_json(quoted)?),
+        None if answer.starts_with("null") => None,
+        None => return None,
+    };
+    let status = value("status")?.strip_prefix('"')?;
+    let status = Status::parse(&status[..status.find('"')?])?;
+
+    Some(Record {
+        day: number("day")?.try_into().ok()?,
+        part: number("part")?.try_into().ok()?,
+        answer,
+        duration: Duration::from_nanos(number("duration_ns")?),
+        status,
+    })
+}
+
+/// Benchmark statistics columns are left empty outside of `--bench` runs,
+/// allocation columns without the `alloc-stats` feature and `error` unless a part panicked or timed out.
+pub const CSV_HEADER: &str = "day,part,answer,duration_ns,status,iterations,min_ns,median_ns,mean_ns,stddev_ns,allocations,allocated_bytes,peak_bytes,error";
+
+pub fn to_csv(day: u8, part: u8, result: &PartResult) -> String {
+    let stats = match &result.stats {
+        Some(stats) => format!(
+            ",{},{},{},{},{}",
+            stats.iterations,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ),
+        None => ",,,,,".into(),
+    };
+
+    let alloc = match &result.alloc {
+        Some(alloc) => format!(",{},{},{}", alloc.allocations, alloc.allocated, alloc.peak),
+        None => ",,,".into(),
+    };
+
+    format!(
+        "{},{},{},{},{}{}{},{}",
+        day,
+        part,
+        escape_csv(result.answer.as_deref().unwrap_or_default()),
+        result.elapsed.as_nanos(),
+        result.status.as_str(),
+        stats,
+        alloc,
+        escape_csv(result.error.as_deref().unwrap_or_default())
+    )
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Unescapes a JSON string up to its closing quote, the opening quote already stripped.
+fn unescape_json(quoted: &str) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = quoted.chars();
+    loop {
+        match chars.next()? {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let code: String = chars.by_ref().take(4).collect();
+                    value.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bench::BenchStats, memory::AllocStats};
+
+    fn result(answer: Option<&str>) -> PartResult {
+        PartResult {
+            answer: answer.map(String::from),
+            elapsed: Duration::from_micros(55),
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            stats: None,
+            alloc: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            to_json(8, 1, &result(Some("5"))),
+            r#"{"day":8,"part":1,"answer":"5","duration_ns":55000,"status":"solved"}"#
+        );
+        assert_eq!(
+            to_json(25, 2, &result(None)),
+            r#"{"day":25,"part":2,"answer":null,"duration_ns":55000,"status":"unsolved"}"#
+        );
+        assert_eq!(
+            to_json(1, 1, &result(Some("a\"b\\c\n"))),
+            r#"{"day":1,"part":1,"answer":"a\"b\\c\n","duration_ns":55000,"status":"solved"}"#
+        );
+    }
+
+    #[test]
+    fn test_from_json() {
+        for (day, part, answer) in [
+            (8, 1, Some("5")),
+            (25, 2, None),
+            (1, 1, Some("a\"b\\c\n\u{1}")),
+        ] {
+            let result = result(answer);
+            let record = to_json(day, part, &result);
+            assert_eq!(
+                from_json(&record),
+                Some(Record {
+                    day,
+                    part,
+                    answer: answer.map(String::from),
+                    duration: result.elapsed,
+                    status: result.status,
+                }),
+                "{record}"
+            );
+        }
+        assert_eq!(from_json("Day 01"), None);
+    }
+
+    #[test]
+    fn test_bench_stats() {
+        let mut result = result(Some("5"));
+        result.stats = Some(BenchStats {
+            iterations: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            mean: Duration::from_nanos(25),
+            stddev: Duration::from_nanos(5),
+        });
+
+        assert_eq!(
+            to_json(8, 1, &result),
+            r#"{"day":8,"part":1,"answer":"5","duration_ns":55000,"status":"solved","iterations":3,"min_ns":10,"median_ns":20,"mean_ns":25,"stddev_ns":5}"#
+        );
+        assert_eq!(to_csv(8, 1, &result), "8,1,5,55000,solved,3,10,20,25,5,,,,");
+    }
+
+    #[test]
+    fn test_alloc_stats() {
+        let mut result = result(Some("5"));
+        result.alloc = Some(AllocStats {
+            allocations: 2,
+            allocated: 2048,
+            peak: 1024,
+        });
+
+        assert_eq!(
+            to_json(8, 1, &result),
+            r#"{"day":8,"part":1,"answer":"5","duration_ns":55000,"status":"solved","allocations":2,"allocated_bytes":2048,"peak_bytes":1024}"#
+        );
+        assert_eq!(
+            to_csv(8, 1, &result),
+            "8,1,5,55000,solved,,,,,,2,2048,1024,"
+        );
+
+        let mut out = String::new();
+        OutputFormat::Pretty.write_result(&mut out, 8, 1, &result);
+        assert!(out.contains("2 allocations, 2.00 KiB allocated, 1.00 KiB peak"));
+    }
+
+    #[test]
+    fn test_failed_parts() {
+        let result = PartResult {
+            elapsed: Duration::from_micros(55),
+            ..PartResult::failed(
+                Status::Panicked,
+                "panicked at src/bin/2020-22.rs:40:17: draw".into(),
+            )
+        };
+
+        assert_eq!(
+            to_json(22, 1, &result),
+            r#"{"day":22,"part":1,"answer":null,"duration_ns":55000,"status":"panicked","error":"panicked at src/bin/2020-22.rs:40:17: draw"}"#
+        );
+        assert_eq!(
+            to_csv(22, 1, &result),
+            "22,1,,55000,panicked,,,,,,,,,panicked at src/bin/2020-22.rs:40:17: draw"
+        );
+
+        let mut out = String::new();
+        OutputFormat::Pretty.write_result(&mut out, 22, 1, &result);
+        assert!(out.contains("PANICKED"));
+
+        let result = PartResult::failed(Status::Error, "invalid input: no cups".into());
+        assert!(to_json(23, 2, &result)
+            .contains(r#""status":"error","error":"invalid input: no cups""#));
+        out.clear();
+        OutputFormat::Pretty.write_result(&mut out, 23, 2, &result);
+        assert!(out.contains("ERROR"));
+    }
+
+    #[test]
+    fn test_parse_step() {
+        let parsed = PartResult {
+            status: Status::Solved,
+            ..result(None)
+        };
+        let mut out = String::new();
+        OutputFormat::Pretty.write_part_heading(&mut out, PARSE_STEP);
+        OutputFormat::Pretty.write_result(&mut out, 8, PARSE_STEP, &parsed);
+        assert_eq!(
+            out,
+            format!(
+                "🎄 {ANSI_BOLD}Parse{ANSI_RESET} 🎄\n{ANSI_ITALIC}(elapsed: 55.00µs){ANSI_RESET}\n"
+            )
+        );
+        assert!(to_json(8, PARSE_STEP, &parsed).starts_with(r#"{"day":8,"part":0,"answer":null"#));
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(8, 1, &result(Some("5"))),
+            "8,1,5,55000,solved,,,,,,,,,"
+        );
+        assert_eq!(
+            to_csv(25, 2, &result(None)),
+            "25,2,,55000,unsolved,,,,,,,,,"
+        );
+        assert_eq!(
+            to_csv(21, 2, &result(Some("mxmxvkd,sqjhc"))),
+            "21,2,\"mxmxvkd,sqjhc\",55000,solved,,,,,,,,,"
+        );
+    }
+}
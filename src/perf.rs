@@ -0,0 +1,319 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Relative slowdown above which `cargo perf compare` flags a part, 10%.
+pub const DEFAULT_THRESHOLD: f64 = 0.1;
+
+/// Slowdowns smaller than this are treated as noise, however large they are relatively.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+pub const HISTORY_HEADER: &str = "run,rev,date,day,part,duration_ns";
+
+/// Timing of one solved part, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Numbers the runs in a history file, see [`next_run`].
+    pub run: u64,
+    pub rev: String,
+    pub date: String,
+    pub day: u8,
+    pub part: u8,
+    pub duration: Duration,
+}
+
+/// Timings are machine specific, so they are kept in the target directory next to the builds
+/// they measure, `target/perf/YYYY` or `$CARGO_TARGET_DIR/perf/YYYY` if set.
+fn perf_dir(year: u16) -> PathBuf {
+    let target = env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || Path::new(env!("CARGO_MANIFEST_DIR")).join("target"),
+        PathBuf::from,
+    );
+    target.join("perf").join(year.to_string())
+}
+
+pub fn history_path(year: u16) -> PathBuf {
+    perf_dir(year).join("history.csv")
+}
+
+/// Medians recorded by `cargo bench`. Kept apart from the history, as a benchmark run usually
+/// covers only some of the days.
+pub fn bench_history_path(year: u16) -> PathBuf {
+    perf_dir(year).join("bench.csv")
+}
+
+/// Short hash of `HEAD`, suffixed with `-dirty` if tracked files have uncommitted changes.
+pub fn git_rev() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(rev) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(changes) if !changes.is_empty() => format!("{rev}-dirty"),
+            _ => rev,
+        },
+        None => "unknown".into(),
+    }
+}
+
+/// The current time in UTC, e.g. `2020-12-01T05:00:00Z`.
+pub fn now_utc() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    format_utc(secs)
+}
+
+fn format_utc(secs: u64) -> String {
+    // converts days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let days = (secs / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    let time = secs % 86400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+pub fn append_history(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let is_new = !path.exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    if is_new {
+        writeln!(file, "{HISTORY_HEADER}")?;
+    }
+    for entry in entries {
+        writeln!(
+            file,
+            "{},{},{},{},{},{}",
+            entry.run,
+            entry.rev,
+            entry.date,
+            entry.day,
+            entry.part,
+            entry.duration.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns an empty history if the file does not exist yet.
+pub fn read_history(path: &Path) -> Result<Vec<Entry>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("{}: {e}", path.display())),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| ![HISTORY_HEADER, ""].contains(line))
+        .map(|(i, line)| {
+            parse_entry(line).ok_or(format!("{}: invalid line {}", path.display(), i + 1))
+        })
+        .collect()
+}
+
+fn parse_entry(line: &str) -> Option<Entry> {
+    let fields: Vec<&str> = line.split(',').collect();
+    let [run, rev, date, day, part, duration] = fields[..] else {
+        return None;
+    };
+    Some(Entry {
+        run: run.parse().ok()?,
+        rev: rev.to_string(),
+        date: date.to_string(),
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        duration: Duration::from_nanos(duration.parse().ok()?),
+    })
+}
+
+/// The number for a run appended to `history`, one more than the latest.
+pub fn next_run(history: &[Entry]) -> u64 {
+    history.iter().map(|entry| entry.run).max().unwrap_or(0) + 1
+}
+
+/// The latest timing of a day's part, only from revisions starting with `rev` if given.
+pub fn latest<'a>(history: &'a [Entry], day: u8, part: u8, rev: Option<&str>) -> Option<&'a Entry> {
+    history.iter().rev().find(|entry| {
+        (entry.day, entry.part) == (day, part) && rev.is_none_or(|rev| entry.rev.starts_with(rev))
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part was never solved before, or not at the baseline revision.
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change, e.g. `0.25` if the part became 25% slower.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        (baseline > 0.0).then(|| self.current.as_secs_f64() / baseline - 1.0)
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        let slowdown = self.baseline.map_or(Duration::ZERO, |baseline| {
+            self.current.saturating_sub(baseline)
+        });
+        slowdown > NOISE_FLOOR && self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// The part of `history` recorded before `current`, all of it if `current` is empty.
+pub fn earlier<'a>(history: &'a [Entry], current: &[Entry]) -> &'a [Entry] {
+    // runs are appended in order, so earlier runs come before the first entry of this one.
+    let end = current
+        .first()
+        .and_then(|current| history.iter().position(|entry| entry.run >= current.run));
+    &history[..end.unwrap_or(history.len())]
+}
+
+/// Compares each part of `current` against its [`latest`] timing in `earlier`, so a part left
+/// out of the previous run is compared against the run before.
+pub fn compare(earlier: &[Entry], current: &[Entry], rev: Option<&str>) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|entry| Comparison {
+            day: entry.day,
+            part: entry.part,
+            baseline: latest(earlier, entry.day, entry.part, rev).map(|base| base.duration),
+            current: entry.duration,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(run: u64, rev: &str, day: u8, part: u8, millis: u64) -> Entry {
+        Entry {
+            run,
+            rev: rev.into(),
+            date: "2020-12-01T05:00:00Z".into(),
+            day,
+            part,
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc(1606798800), "2020-12-01T05:00:00Z");
+        assert_eq!(format_utc(1709208000), "2024-02-29T12:00:00Z");
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-perf-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let entries = vec![entry(1, "abc1234", 1, 1, 37), entry(1, "abc1234", 1, 2, 33)];
+
+        append_history(&path, &entries).unwrap();
+        append_history(&path, &entries[..1]).unwrap();
+        let history = read_history(&path).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[..2], entries[..]);
+
+        fs::remove_file(path).unwrap();
+        assert!(parse_entry("1,abc1234,2020-12-01T05:00:00Z,1,1").is_none());
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(next_run(&[]), 1);
+
+        let history = [
+            entry(1, "aaaaaaa", 1, 1, 100),
+            entry(1, "aaaaaaa", 1, 2, 100),
+            entry(2, "bbbbbbb", 1, 1, 200),
+            entry(3, "ccccccc", 1, 1, 125),
+            entry(3, "ccccccc", 1, 2, 100),
+            entry(3, "ccccccc", 2, 1, 100),
+        ];
+        assert_eq!(next_run(&history), 4);
+
+        let (earlier, current) = (earlier(&history, &history[3..]), &history[3..]);
+        assert_eq!(earlier, &history[..3]);
+        assert_eq!(super::earlier(&history, &[]), &history[..]);
+
+        let comparisons = compare(earlier, current, None);
+        assert_eq!(comparisons[0].baseline, Some(Duration::from_millis(200)));
+        assert!(!comparisons[0].regressed(DEFAULT_THRESHOLD));
+        // run 2 left out part 2, so it is compared against run 1.
+        assert_eq!(comparisons[1].baseline, Some(Duration::from_millis(100)));
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].regressed(DEFAULT_THRESHOLD));
+
+        let comparisons = compare(earlier, current, Some("aaa"));
+        assert_eq!(comparisons[0].change(), Some(0.25));
+        assert!(comparisons[0].regressed(DEFAULT_THRESHOLD));
+        assert_eq!(compare(earlier, current, Some("ccc"))[0].baseline, None);
+
+        let tiny = Comparison {
+            day: 1,
+            part: 1,
+            baseline: Some(Duration::from_micros(4)),
+            current: Duration::from_micros(8),
+        };
+        assert!(!tiny.regressed(DEFAULT_THRESHOLD));
+    }
+
+    #[test]
+    fn test_latest() {
+        let history = [
+            entry(1, "aaaaaaa", 1, 1, 100),
+            entry(2, "bbbbbbb", 1, 1, 90),
+            entry(2, "bbbbbbb", 1, 2, 80),
+        ];
+        assert_eq!(latest(&history, 1, 1, None), Some(&history[1]));
+        assert_eq!(latest(&history, 1, 1, Some("aaa")), Some(&history[0]));
+        assert_eq!(latest(&history, 1, 2, Some("aaa")), None);
+        assert_eq!(latest(&history, 2, 1, None), None);
+    }
+}
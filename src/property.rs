@@ -0,0 +1,172 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{guard::catch_panic, rng::Rng};
+use std::{env, fmt::Debug};
+
+/// Number of generated inputs per property, override with `AOC_PROPERTY_CASES`.
+pub const DEFAULT_CASES: usize = 200;
+pub const CASES_VAR: &str = "AOC_PROPERTY_CASES";
+/// Seed for generating inputs, fixed so that runs are reproducible.
+pub const DEFAULT_SEED: u64 = 2020;
+/// Set to try other inputs than the default seed generates.
+pub const SEED_VAR: &str = "AOC_PROPERTY_SEED";
+
+/// Upper bound on shrinking steps, in case `shrink` never runs out of candidates.
+const MAX_SHRINKS: usize = 10_000;
+
+/// Checks `test` against inputs from `generate`, e.g. by comparing an optimised solver with a
+/// brute-force reference. A panic counts as a failure.
+///
+/// On failure, smaller inputs from `shrink` are tried for as long as one of them still fails,
+/// and the test panics with the smallest failing input and the seed to reproduce it.
+/// Inputs a test cannot judge, e.g. ones that break a puzzle's assumptions, should pass.
+pub fn check<T: Debug>(
+    generate: impl Fn(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    test: impl Fn(&T) -> Result<(), String>,
+) {
+    let cases = env::var(CASES_VAR)
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    let seed = env::var(SEED_VAR)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+
+    let run = |input: &T| catch_panic(|| test(input)).and_then(|result| result);
+
+    let mut rng = Rng::new(seed);
+    for case in 1..=cases {
+        let input = generate(&mut rng);
+        let Err(error) = run(&input) else {
+            continue;
+        };
+
+        let (input, error, shrinks) = minimize(input, error, &shrink, run);
+        panic!(
+            "property failed on case {case} (rerun with {SEED_VAR}={seed}), \
+             minimal input after {shrinks} shrinks:\n{input:?}\n{error}"
+        );
+    }
+}
+
+/// Replaces `input` with the first failing candidate from `shrink` until none fails.
+fn minimize<T>(
+    mut input: T,
+    mut error: String,
+    shrink: impl Fn(&T) -> Vec<T>,
+    run: impl Fn(&T) -> Result<(), String>,
+) -> (T, String, usize) {
+    let mut shrinks = 0;
+    'shrinking: while shrinks < MAX_SHRINKS {
+        for candidate in shrink(&input) {
+            if let Err(candidate_error) = run(&candidate) {
+                (input, error) = (candidate, candidate_error);
+                shrinks += 1;
+                continue 'shrinking;
+            }
+        }
+        break;
+    }
+    (input, error, shrinks)
+}
+
+/// Fails with both values if they differ.
+pub fn expect_eq<T: PartialEq + Debug>(actual: T, expected: T) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!("expected {expected:?}, got {actual:?}"))
+    }
+}
+
+/// Smaller versions of `items`: chunks of halving size removed from every position,
+/// down to single items.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut candidates = Vec::new();
+    let mut size = items.len();
+    while size > 0 {
+        for start in (0..=items.len() - size).step_by(size) {
+            let mut candidate = items[..start].to_vec();
+            candidate.extend_from_slice(&items[start + size..]);
+            candidates.push(candidate);
+        }
+        size /= 2;
+    }
+    candidates
+}
+
+/// Smaller numbers than `n`, the smallest first.
+pub fn shrink_number(n: usize) -> Vec<usize> {
+    let mut candidates = vec![0, n / 2, n.saturating_sub(1)];
+    candidates.retain(|&candidate| candidate < n);
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_vec() {
+        assert_eq!(
+            shrink_vec(&[1, 2, 3, 4]),
+            vec![
+                vec![],
+                vec![3, 4],
+                vec![1, 2],
+                vec![2, 3, 4],
+                vec![1, 3, 4],
+                vec![1, 2, 4],
+                vec![1, 2, 3],
+            ]
+        );
+        assert!(shrink_vec::<u8>(&[]).is_empty());
+        assert_eq!(shrink_number(10), vec![0, 5, 9]);
+        assert!(shrink_number(0).is_empty());
+    }
+
+    #[test]
+    fn test_passing_property() {
+        check(
+            |rng| rng.range(0..=100),
+            |&n| shrink_number(n),
+            |&n| expect_eq(n * 2, n + n),
+        );
+    }
+
+    #[test]
+    fn test_shrinks_failures() {
+        // "sums below 10" fails for most inputs. shrinking can't merge items, so it may stop at
+        // e.g. [4, 6], but lowering any item by one passes once the sum is exactly 10.
+        let failure = catch_panic(|| {
+            check(
+                |rng| (0..rng.range(1..=8)).map(|_| rng.range(0..=20)).collect(),
+                |items: &Vec<usize>| {
+                    let mut candidates = shrink_vec(items);
+                    for (i, &item) in items.iter().enumerate() {
+                        candidates.extend(shrink_number(item).into_iter().map(|smaller| {
+                            let mut candidate = items.clone();
+                            candidate[i] = smaller;
+                            candidate
+                        }));
+                    }
+                    candidates
+                },
+                |items| match items.iter().sum::<usize>() {
+                    sum if sum < 10 => Ok(()),
+                    sum => Err(format!("sum is {sum}")),
+                },
+            )
+        })
+        .unwrap_err();
+
+        assert!(failure.contains(SEED_VAR), "{failure}");
+        // the panic message is flattened to one line by `catch_panic`.
+        assert!(failure.contains("] sum is 10"), "{failure}");
+    }
+}
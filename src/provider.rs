@@ -0,0 +1,463 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{read_answers_file, write_answer};
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Serves puzzles from a local directory instead of adventofcode.com if set, see [`LocalProvider`].
+pub const PROVIDER_DIR_VAR: &str = "AOC_PROVIDER_DIR";
+
+/// Everything the template needs from adventofcode.com.
+pub trait PuzzleProvider {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, ProviderError>;
+    /// The puzzle description as markdown.
+    fn fetch_description(&self, year: u16, day: u8) -> Result<String, ProviderError>;
+    fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ProviderError>;
+}
+
+/// [`LocalProvider`] if `AOC_PROVIDER_DIR` is set, [`AocCli`] otherwise.
+pub fn from_env() -> Box<dyn PuzzleProvider> {
+    match env::var_os(PROVIDER_DIR_VAR) {
+        Some(dir) => Box::new(LocalProvider::new(dir)),
+        None => Box::new(AocCli),
+    }
+}
+
+#[derive(Debug)]
+pub enum ProviderError {
+    CommandNotFound,
+    BadExitStatus,
+    Io { path: PathBuf, reason: io::Error },
+    InvalidAnswers(String),
+}
+
+impl ProviderError {
+    fn io(path: &Path) -> impl FnOnce(io::Error) -> Self + '_ {
+        |reason| ProviderError::Io {
+            path: path.to_path_buf(),
+            reason,
+        }
+    }
+}
+
+impl Display for ProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProviderError::CommandNotFound => write!(
+                f,
+                "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            ProviderError::BadExitStatus => write!(f, "aoc-cli exited with a non-zero status."),
+            ProviderError::Io { path, reason } => {
+                write!(f, "could not access \"{}\": {reason}", path.display())
+            }
+            ProviderError::InvalidAnswers(e) => write!(f, "invalid answers file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+/// The response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect,
+    /// Rate limited, no verdict was given.
+    TooSoon,
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response that could not be interpreted, kept verbatim.
+    Unknown(String),
+}
+
+impl Submission {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Submission::Correct => "correct",
+            Submission::Incorrect => "incorrect",
+            Submission::TooSoon => "too_soon",
+            Submission::WrongLevel => "wrong_level",
+            Submission::Unknown(_) => "unknown",
+        }
+    }
+
+    /// Interprets the text adventofcode.com responds with.
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            Submission::Correct
+        } else if response.contains("That's not the right answer") {
+            Submission::Incorrect
+        } else if response.contains("You gave an answer too recently") {
+            Submission::TooSoon
+        } else if response.contains("You don't seem to be solving the right level") {
+            Submission::WrongLevel
+        } else {
+            Submission::Unknown(response.trim().to_string())
+        }
+    }
+}
+
+/// Talks to adventofcode.com through [aoc-cli](https://github.com/scarvalhojr/aoc-cli/).
+pub struct AocCli;
+
+impl AocCli {
+    fn call(args: &[String], capture: bool) -> Result<String, ProviderError> {
+        if cfg!(debug_assertions) {
+            println!("Calling >aoc with: {}", args.join(" "));
+        }
+
+        let stdout = if capture {
+            Stdio::piped()
+        } else {
+            Stdio::inherit()
+        };
+
+        let output = Command::new("aoc")
+            .args(args)
+            .stdout(stdout)
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|_| ProviderError::CommandNotFound)?;
+
+        if output.status.success() {
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        } else {
+            Err(ProviderError::BadExitStatus)
+        }
+    }
+
+    /// aoc-cli only writes downloads to files, so they are routed through a temporary one.
+    fn download(year: u16, day: u8, only: &str, file_flag: &str) -> Result<String, ProviderError> {
+        let dir = create_temp_dir(&format!("aoc-{year}-{day:02}"))?;
+        let path = dir.join(file_flag.trim_start_matches("--"));
+        let args = build_args(
+            "download",
+            &[
+                "--overwrite".into(),
+                only.into(),
+                file_flag.into(),
+                path.display().to_string(),
+            ],
+            year,
+            day,
+        );
+
+        let contents = Self::call(&args, false)
+            .and_then(|_| fs::read_to_string(&path).map_err(ProviderError::io(&path)));
+        let _ = fs::remove_dir_all(&dir);
+        contents
+    }
+}
+
+/// A new, empty directory in the system's temp dir that no other process or call shares.
+/// Creating it fails if the name is taken, so nothing planted there beforehand is written to.
+fn create_temp_dir(prefix: &str) -> Result<PathBuf, ProviderError> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.subsec_nanos());
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let dir = env::temp_dir().join(format!("{prefix}-{}-{nanos}-{count}", process::id()));
+    fs::create_dir(&dir).map_err(ProviderError::io(&dir))?;
+    Ok(dir)
+}
+
+impl PuzzleProvider for AocCli {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, ProviderError> {
+        Self::download(year, day, "--input-only", "--input-file")
+    }
+
+    fn fetch_description(&self, year: u16, day: u8) -> Result<String, ProviderError> {
+        Self::download(year, day, "--puzzle-only", "--puzzle-file")
+    }
+
+    fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ProviderError> {
+        let args = build_args("submit", &[], year, day);
+        let args = [args, vec![part.to_string(), answer.to_string()]].concat();
+
+        let response = Self::call(&args, true)?;
+        print!("{response}");
+        Ok(Submission::from_response(&response))
+    }
+}
+
+fn build_args(command: &str, args: &[String], year: u16, day: u8) -> Vec<String> {
+    let mut cmd_args = args.to_vec();
+
+    cmd_args.push("--year".into());
+    cmd_args.push(year.to_string());
+
+    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+
+    cmd_args
+}
+
+/// Serves puzzles from a directory laid out like `src`, with everything for a year in one folder:
+/// `YYYY/NN.txt` (input), `YYYY/NN.md` (description) and `YYYY/NN.toml` (correct answers).
+/// Lets the download and submit flows run offline.
+pub struct LocalProvider {
+    root: PathBuf,
+}
+
+impl LocalProvider {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, year: u16, day: u8, extension: &str) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("{day:02}.{extension}"))
+    }
+
+    fn read(&self, year: u16, day: u8, extension: &str) -> Result<String, ProviderError> {
+        let path = self.path(year, day, extension);
+        fs::read_to_string(&path).map_err(ProviderError::io(&path))
+    }
+}
+
+impl PuzzleProvider for LocalProvider {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, ProviderError> {
+        self.read(year, day, "txt")
+    }
+
+    fn fetch_description(&self, year: u16, day: u8) -> Result<String, ProviderError> {
+        self.read(year, day, "md")
+    }
+
+    fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ProviderError> {
+        let path = self.path(year, day, "toml");
+        let answers = read_answers_file(&path).map_err(ProviderError::InvalidAnswers)?;
+
+        let verdict = match answers.as_ref().and_then(|answers| answers.get(part)) {
+            Some(expected) if expected == answer => Submission::Correct,
+            Some(_) => Submission::Incorrect,
+            None => Submission::Unknown(format!("no answer for part {part} in {}", path.display())),
+        };
+        Ok(verdict)
+    }
+}
+
+/// Every submission is logged next to the answers file, e.g. `src/answers/YYYY/NN.log`.
+pub fn submissions_log_path(answers_path: &Path) -> PathBuf {
+    answers_path.with_extension("log")
+}
+
+/// The verdict for `answer` if it follows from earlier submissions, so it is not sent again.
+/// A recorded answer settles the part, any other answer to it is incorrect.
+pub fn known_verdict(
+    answers_path: &Path,
+    part: u8,
+    answer: &str,
+) -> Result<Option<Submission>, ProviderError> {
+    let answers = read_answers_file(answers_path).map_err(ProviderError::InvalidAnswers)?;
+    if let Some(expected) = answers.as_ref().and_then(|answers| answers.get(part)) {
+        return Ok(Some(if expected == answer {
+            Submission::Correct
+        } else {
+            Submission::Incorrect
+        }));
+    }
+
+    let log_path = submissions_log_path(answers_path);
+    let log = match fs::read_to_string(&log_path) {
+        Ok(log) => log,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(ProviderError::io(&log_path)(e)),
+    };
+
+    let incorrect = format!("{part}\t{}\t{answer}", Submission::Incorrect.as_str());
+    Ok(log
+        .lines()
+        .any(|line| line == incorrect)
+        .then_some(Submission::Incorrect))
+}
+
+/// Submits `answer` and records the verdict: it is appended to the submissions log and,
+/// if correct, the answer is stored in `answers_path` for `cargo verify`.
+pub fn submit_and_record(
+    provider: &dyn PuzzleProvider,
+    answers_path: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Submission, ProviderError> {
+    let verdict = provider.submit(year, day, part, answer)?;
+
+    let log_path = submissions_log_path(answers_path);
+    if let Some(dir) = log_path.parent() {
+        fs::create_dir_all(dir).map_err(ProviderError::io(dir))?;
+    }
+    fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .and_then(|mut log| writeln!(log, "{part}\t{}\t{answer}", verdict.as_str()))
+        .map_err(ProviderError::io(&log_path))?;
+
+    if verdict == Submission::Correct {
+        read_answers_file(answers_path).map_err(ProviderError::InvalidAnswers)?;
+        write_answer(answers_path, part, answer).map_err(ProviderError::io(answers_path))?;
+    }
+
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-provider-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("2020")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_create_temp_dir() {
+        let first = create_temp_dir("aoc-provider-temp").unwrap();
+        let second = create_temp_dir("aoc-provider-temp").unwrap();
+        assert_ne!(first, second);
+        assert!(first.is_dir() && second.is_dir());
+
+        // an existing directory is never reused.
+        assert!(fs::create_dir(&first).is_err());
+        fs::remove_dir(first).unwrap();
+        fs::remove_dir(second).unwrap();
+    }
+
+    #[test]
+    fn test_from_response() {
+        assert_eq!(
+            Submission::from_response("That's the right answer! You are one gold star closer."),
+            Submission::Correct
+        );
+        assert_eq!(
+            Submission::from_response("That's not the right answer; your answer is too low."),
+            Submission::Incorrect
+        );
+        assert_eq!(
+            Submission::from_response("You gave an answer too recently; you have 30s left."),
+            Submission::TooSoon
+        );
+        assert_eq!(
+            Submission::from_response("Teapot"),
+            Submission::Unknown("Teapot".into())
+        );
+    }
+
+    #[test]
+    fn test_local_provider() {
+        let dir = temp_dir("local");
+        fs::write(dir.join("2020/08.txt"), "nop +0\n").unwrap();
+        fs::write(dir.join("2020/08.md"), "## --- Day 8 ---\n").unwrap();
+        fs::write(dir.join("2020/08.toml"), "part_one = 5\n").unwrap();
+        let provider = LocalProvider::new(&dir);
+
+        assert_eq!(provider.fetch_input(2020, 8).unwrap(), "nop +0\n");
+        assert_eq!(
+            provider.fetch_description(2020, 8).unwrap(),
+            "## --- Day 8 ---\n"
+        );
+        assert!(provider.fetch_input(2020, 9).is_err());
+
+        assert_eq!(
+            provider.submit(2020, 8, 1, "5").unwrap(),
+            Submission::Correct
+        );
+        assert_eq!(
+            provider.submit(2020, 8, 1, "6").unwrap(),
+            Submission::Incorrect
+        );
+        assert!(matches!(
+            provider.submit(2020, 8, 2, "5").unwrap(),
+            Submission::Unknown(_)
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit_and_record() {
+        let dir = temp_dir("submit");
+        fs::write(dir.join("2020/08.toml"), "part_one = 5\npart_two = 8\n").unwrap();
+        let provider = LocalProvider::new(&dir);
+        let store = dir.join("answers/2020/08.toml");
+        fs::create_dir_all(store.parent().unwrap()).unwrap();
+        fs::write(&store, "# answers for your real input.\n").unwrap();
+
+        assert_eq!(known_verdict(&store, 1, "4").unwrap(), None);
+        assert_eq!(
+            submit_and_record(&provider, &store, 2020, 8, 1, "4").unwrap(),
+            Submission::Incorrect
+        );
+        assert_eq!(
+            known_verdict(&store, 1, "4").unwrap(),
+            Some(Submission::Incorrect)
+        );
+        assert_eq!(known_verdict(&store, 1, "5").unwrap(), None);
+
+        assert_eq!(
+            submit_and_record(&provider, &store, 2020, 8, 1, "5").unwrap(),
+            Submission::Correct
+        );
+        assert_eq!(
+            read_answers_file(&store).unwrap(),
+            Some(Answers {
+                part_one: Some("5".into()),
+                part_two: None,
+            })
+        );
+        assert_eq!(
+            known_verdict(&store, 1, "5").unwrap(),
+            Some(Submission::Correct)
+        );
+        assert_eq!(
+            known_verdict(&store, 1, "7").unwrap(),
+            Some(Submission::Incorrect)
+        );
+        assert_eq!(
+            fs::read_to_string(&store).unwrap(),
+            "# answers for your real input.\npart_one = \"5\"\n"
+        );
+        assert_eq!(
+            fs::read_to_string(submissions_log_path(&store)).unwrap(),
+            "1\tincorrect\t4\n1\tcorrect\t5\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
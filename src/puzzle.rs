@@ -0,0 +1,242 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{input::data_dir, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::path::PathBuf;
+
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    data_dir("puzzles", year).join(format!("{day:02}.md"))
+}
+
+/// The title from the first heading of a puzzle description, e.g. `Day 1: Report Repair`.
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find(|line| line.starts_with('#'))?;
+    let title = heading
+        .trim_start_matches('#')
+        .trim_start()
+        .trim_start_matches('\\')
+        .trim_matches(|c: char| c == '-' || c.is_whitespace());
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Renders a puzzle description as written by aoc-cli for the terminal.
+/// Headings and emphasis are printed bold, inline code italic and code blocks indented.
+pub fn render(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut out = String::new();
+    let mut in_code_block = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            if !line.is_empty() {
+                out.push_str("    ");
+                out.push_str(line);
+            }
+        } else if is_underline(lines.get(i)) && !line.trim().is_empty() {
+            // setext heading, e.g. `\--- Day 4: Passport Processing ---` followed by `-----`.
+            i += 1;
+            out.push_str(&heading(line));
+        } else if let Some(text) = line.trim_start_matches('#').strip_prefix(' ') {
+            if line.starts_with('#') {
+                out.push_str(&heading(text));
+            } else {
+                out.push_str(&render_inline(line));
+            }
+        } else if let Some((indent, item)) = list_item(line) {
+            out.push_str(indent);
+            out.push_str("• ");
+            out.push_str(&render_inline(item));
+        } else {
+            out.push_str(&render_inline(line));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+fn is_underline(line: Option<&&str>) -> bool {
+    line.is_some_and(|line| {
+        let line = line.trim();
+        line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+    })
+}
+
+fn heading(text: &str) -> String {
+    format!(
+        "{ANSI_BOLD}{}{ANSI_RESET}",
+        strip_styles(&render_inline(text))
+    )
+}
+
+fn list_item(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    ["* ", "- ", "+ "]
+        .iter()
+        .find_map(|marker| trimmed.strip_prefix(marker))
+        .map(|item| (indent, item))
+}
+
+fn strip_styles(text: &str) -> String {
+    [ANSI_BOLD, ANSI_ITALIC, ANSI_RESET]
+        .iter()
+        .fold(text.to_string(), |text, code| text.replace(code, ""))
+}
+
+/// Replaces `[text](url)` with `text`.
+fn strip_links(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find("](") {
+        // urls in angle brackets may contain parentheses, e.g. `(<javascript:void(0);>)`.
+        let closing = if rest[start + 2..].starts_with('<') {
+            ">)"
+        } else {
+            ")"
+        };
+        let Some(end) = rest[start..]
+            .find(closing)
+            .map(|end| end + closing.len() - 1)
+        else {
+            break;
+        };
+        let text = &rest[..start];
+        match text.rfind('[') {
+            Some(open) => {
+                out.push_str(&text[..open]);
+                out.push_str(&text[open + 1..]);
+            }
+            None => out.push_str(text),
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn render_inline(line: &str) -> String {
+    let line = strip_links(line);
+    let mut out = String::with_capacity(line.len());
+    let (mut emphasis, mut code) = (false, false);
+
+    let apply = |out: &mut String, emphasis: bool, code: bool| {
+        out.push_str(ANSI_RESET);
+        if emphasis {
+            out.push_str(ANSI_BOLD);
+        }
+        if code {
+            out.push_str(ANSI_ITALIC);
+        }
+    };
+
+    let mut chars = line.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if !code => {
+                if let Some((_, escaped)) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            '`' => {
+                code = !code;
+                apply(&mut out, emphasis, code);
+            }
+            '*' => {
+                let strong = chars.peek().is_some_and(|(_, next)| *next == '*');
+                let after = &line[i + if strong { 2 } else { 1 }..];
+                // only treat `*` as a delimiter if it is opening or closing a span,
+                // e.g. not in "2 * 3" or "(`*`)".
+                let opens = !emphasis
+                    && after.starts_with(|c: char| !c.is_whitespace() && c != '`')
+                    && after.contains('*');
+                let closes = emphasis && !out.ends_with(char::is_whitespace);
+                if opens || closes {
+                    if strong {
+                        chars.next();
+                    }
+                    emphasis = !emphasis;
+                    apply(&mut out, emphasis, code);
+                } else {
+                    out.push(c);
+                }
+            }
+            c => out.push(c),
+        }
+    }
+
+    if emphasis || code {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn b(text: &str) -> String {
+        format!("{ANSI_RESET}{ANSI_BOLD}{text}{ANSI_RESET}")
+    }
+
+    fn i(text: &str) -> String {
+        format!("{ANSI_RESET}{ANSI_ITALIC}{text}{ANSI_RESET}")
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(
+            title("## \\--- Day 1: Report Repair ---\n\nAfter saving Christmas"),
+            Some("Day 1: Report Repair".into())
+        );
+        assert_eq!(title("no heading"), None);
+    }
+
+    #[test]
+    fn test_render_inline() {
+        assert_eq!(
+            render_inline("a *valid* one"),
+            format!("a {} one", b("valid"))
+        );
+        assert_eq!(
+            render_inline("the `cid` field"),
+            format!("the {} field", i("cid"))
+        );
+        assert_eq!(render_inline("`2 * 3`"), i("2 * 3"));
+        assert_eq!(render_inline("(`*`)"), format!("({})", i("*")));
+        assert_eq!(
+            render_inline("via [toboggan](https://x.y)."),
+            "via toboggan."
+        );
+        assert_eq!(render_inline("\\--- Day 1 ---"), "--- Day 1 ---");
+        assert_eq!(
+            render_inline("`*2*`"),
+            format!("{ANSI_RESET}{ANSI_ITALIC}{ANSI_RESET}{ANSI_BOLD}{ANSI_ITALIC}2{ANSI_RESET}{ANSI_ITALIC}{ANSI_RESET}")
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let markdown =
+            "\\--- Day 4 ---\n----------\n\nFields:\n\n* `byr`\n\n```\necl:gry\n```\n## Part Two";
+        assert_eq!(
+            render(markdown),
+            format!(
+                "{ANSI_BOLD}--- Day 4 ---{ANSI_RESET}\n\nFields:\n\n• {}\n\n    ecl:gry\n{ANSI_BOLD}Part Two{ANSI_RESET}\n",
+                i("byr")
+            )
+        );
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{path::PathBuf, time::Duration};
+
+/// Surrounds the benchmark table in `README.md`, the table goes between two of them.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+/// Timings of a day's parse step and parts, `None` for steps that were not run or not solved.
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Option<Duration>,
+    pub part_one: Option<Duration>,
+    pub part_two: Option<Duration>,
+}
+
+pub fn readme_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("README.md")
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => format!("`{time:.1?}`"),
+        None => "-".into(),
+    }
+}
+
+pub fn render_table(rows: &[Row]) -> String {
+    let mut table = String::from("## Benchmarks\n\n");
+    table.push_str("| Day | Parse | Part 1 | Part 2 | Solved |\n");
+    table.push_str("| :---: | :---: | :---: | :---: | :---: |\n");
+
+    for row in rows {
+        let stars = [row.part_one, row.part_two]
+            .iter()
+            .filter(|time| time.is_some())
+            .count();
+        table.push_str(&format!(
+            "| [Day {}](./src/year{}/day{:02}.rs) | {} | {} | {} | {} |\n",
+            row.day,
+            row.year,
+            row.day,
+            format_time(row.parse),
+            format_time(row.part_one),
+            format_time(row.part_two),
+            "⭐".repeat(stars)
+        ));
+    }
+
+    // the parse step counts towards the total like it does in `cargo all`.
+    let total: Duration = rows
+        .iter()
+        .flat_map(|row| [row.parse, row.part_one, row.part_two])
+        .flatten()
+        .sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+    table
+}
+
+/// Replaces everything between the first two markers in `readme` with `table`.
+pub fn update(readme: &str, table: &str) -> Result<String, String> {
+    let missing = || format!("expected two \"{MARKER}\" lines to put the table between");
+
+    let start = readme.find(MARKER).ok_or_else(missing)? + MARKER.len();
+    let end = start + readme[start..].find(MARKER).ok_or_else(missing)?;
+
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        table,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        let rows = [
+            Row {
+                year: 2020,
+                day: 1,
+                parse: None,
+                part_one: Some(Duration::from_micros(37)),
+                part_two: Some(Duration::from_micros(33)),
+            },
+            Row {
+                year: 2020,
+                day: 2,
+                parse: Some(Duration::from_micros(250)),
+                part_one: Some(Duration::from_micros(1500)),
+                part_two: None,
+            },
+        ];
+
+        assert_eq!(
+            render_table(&rows),
+            "## Benchmarks\n\n\
+             | Day | Parse | Part 1 | Part 2 | Solved |\n\
+             | :---: | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](./src/year2020/day01.rs) | - | `37.0µs` | `33.0µs` | ⭐⭐ |\n\
+             | [Day 2](./src/year2020/day02.rs) | `250.0µs` | `1.5ms` | - | ⭐ |\n\
+             \n**Total: 1.82ms**\n"
+        );
+    }
+
+    #[test]
+    fn test_update() {
+        let readme = format!("# AoC\n\n{MARKER}\nold table\n{MARKER}\n\n## Usage\n");
+        let updated = update(&readme, "new table\n").unwrap();
+        assert_eq!(
+            updated,
+            format!("# AoC\n\n{MARKER}\n\nnew table\n\n{MARKER}\n\n## Usage\n")
+        );
+        assert_eq!(update(&updated, "new table\n").unwrap(), updated);
+
+        assert!(update("# AoC\n", "table").is_err());
+        assert!(update(&format!("{MARKER}\n"), "table").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::{Path, PathBuf};
+
+/// Template used by `cargo scaffold` unless `--template` is passed.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+/// Resolves `--template <name|path>`: an existing file, or anything with a directory in it, is
+/// used as is. Anything else names a template in `src/templates`.
+pub fn template_path(name: &str) -> PathBuf {
+    let path = Path::new(name);
+    if path.is_file() || path.components().count() > 1 {
+        return path.to_path_buf();
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("templates")
+        .join(format!("{name}.tmpl"))
+}
+
+/// Replaces `{{name}}` placeholders with the matching values, and `{{{{` with a literal `{{`.
+/// Unknown and unterminated placeholders are errors, so typos do not end up in generated code.
+pub fn render(template: &str, values: &[(&str, &str)]) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        if let Some(escaped) = after.strip_prefix("{{") {
+            out.push_str("{{");
+            rest = escaped;
+            continue;
+        }
+
+        let end = after
+            .find("}}")
+            .ok_or_else(|| format!("unterminated placeholder \"{}\"", &rest[start..]))?;
+
+        let name = after[..end].trim();
+        let (_, value) = values.iter().find(|(key, _)| *key == name).ok_or_else(|| {
+            let known: Vec<&str> = values.iter().map(|(key, _)| *key).collect();
+            format!(
+                "unknown placeholder \"{{{{{name}}}}}\", expected one of: {}",
+                known.join(", ")
+            )
+        })?;
+        out.push_str(value);
+        rest = &after[end + 2..];
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let values = [("day", "7"), ("day_padded", "07")];
+        assert_eq!(
+            render("solution!({{day}}); // {{ day_padded }}, DAY", &values),
+            Ok("solution!(7); // 07, DAY".into())
+        );
+        assert_eq!(
+            render("{{days}}", &values),
+            Err("unknown placeholder \"{{days}}\", expected one of: day, day_padded".into())
+        );
+        assert!(render("{{day", &values).is_err());
+        assert_eq!(
+            render("format!(\"{{{{}}\", {{day}})", &values),
+            Ok("format!(\"{{}}\", 7)".into())
+        );
+    }
+
+    #[test]
+    fn test_template_path() {
+        let missing = std::env::temp_dir().join("missing.tmpl");
+        assert_eq!(template_path(missing.to_str().unwrap()), missing);
+        assert!(template_path("custom").ends_with("src/templates/custom.tmpl"));
+    }
+
+    #[test]
+    fn test_default_template() {
+        let template = std::fs::read_to_string(template_path(DEFAULT_TEMPLATE)).unwrap();
+        let values = [
+            ("day", "7"),
+            ("day_padded", "07"),
+            ("year", "2020"),
+            ("title", "Day 7: Handy Haversacks"),
+        ];
+        let module = render(&template, &values).unwrap();
+        assert!(module.contains("advent_of_code::solution!(2020, 7, usize, usize);"));
+    }
+}
@@ -39,11 +39,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 1, i32, i32);
 
 #[cfg(test)]
 mod tests {
@@ -51,13 +47,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2020, 1);
         assert_eq!(part_one(&input), Some(514579));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2020, 1);
         assert_eq!(part_two(&input), Some(241861950));
     }
 }
@@ -0,0 +1,97 @@
+use advent_of_code::{error::parse_in, SolveError};
+
+/// A password and the policy it was set under.
+pub struct Password {
+    password: String,
+    min: usize,
+    max: usize,
+    letter: char,
+}
+
+impl Password {
+    fn parse(input: &str, line: &str) -> Result<Self, SolveError> {
+        let malformed =
+            || SolveError::parse(input, line, "expected \"<min>-<max> <letter>: <password>\"");
+        let (min, rest) = line.split_once('-').ok_or_else(malformed)?;
+        let (max, rest) = rest.split_once(' ').ok_or_else(malformed)?;
+        let (letter, password) = rest.split_once(": ").ok_or_else(malformed)?;
+
+        Ok(Self {
+            password: password.to_string(),
+            min: parse_in(input, min)?,
+            max: parse_in(input, max)?,
+            letter: parse_in(input, letter)?,
+        })
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Password>, SolveError> {
+    input
+        .lines()
+        .map(|line| Password::parse(input, line))
+        .collect()
+}
+
+pub fn part_one(passwords: &[Password]) -> Option<usize> {
+    let valid_passwords = passwords
+        .iter()
+        .filter(|p| {
+            let mut letter_count = 0;
+            for l in p.password.chars() {
+                if l == p.letter {
+                    letter_count += 1;
+                }
+            }
+            (p.min..=p.max).contains(&letter_count)
+        })
+        .count();
+
+    Some(valid_passwords)
+}
+
+pub fn part_two(passwords: &[Password]) -> Option<usize> {
+    let valid_passwords = passwords
+        .iter()
+        .filter(|p| {
+            let chars: Vec<char> = p.password.chars().collect();
+            let letter = &p.letter;
+            matches!(
+                (
+                    chars.get(p.min - 1) == Some(letter),
+                    chars.get(p.max - 1) == Some(letter),
+                ),
+                (true, false) | (false, true)
+            )
+        })
+        .count();
+
+    Some(valid_passwords)
+}
+
+advent_of_code::solution!(2020, 2, Vec<Password> => usize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2020, 2);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2020, 2);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(1));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "1-3 a: abcde\n1-x b: cdefg\n";
+        assert_eq!(
+            parse(input).err().unwrap().to_string(),
+            "parse error at line 2, column 3: invalid digit found in string (\"x\")"
+        );
+    }
+}
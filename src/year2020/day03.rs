@@ -33,11 +33,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(result)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 3, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -45,13 +41,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2020, 3);
         assert_eq!(part_one(&input), Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2020, 3);
         assert_eq!(part_two(&input), Some(336));
     }
 }
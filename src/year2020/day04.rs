@@ -16,7 +16,7 @@ impl Document {
     fn is_valid(&self) -> bool {
         let (height, unit) = self.hgt.split_at(self.hgt.len() - 2);
         let Ok(height) = height.parse() else {
-            return false
+            return false;
         };
         let valid_height = match unit {
             "cm" => (150..=193).contains(&height),
@@ -94,25 +94,11 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(valid_documents)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 4, usize, usize);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_one(&input), Some(2));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = include_str!("../examples/04-2.txt");
-        assert_eq!(part_two(input), Some(4));
-    }
+    advent_of_code::example_tests!(2020, 4);
 }
@@ -47,11 +47,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 5, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -59,13 +55,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2020, 5);
         assert_eq!(part_one(&input), Some(820));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2020, 5);
         assert_eq!(part_two(&input), Some(356));
     }
 }
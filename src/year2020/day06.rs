@@ -39,11 +39,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(sum)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 6, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -51,13 +47,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = advent_of_code::read_file("examples", 2020, 6);
         assert_eq!(part_one(&input), Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = advent_of_code::read_file("examples", 2020, 6);
         assert_eq!(part_two(&input), Some(6));
     }
 }
@@ -15,7 +15,9 @@ fn parse_rules(input: &str) -> Rules {
         for bag in contained.split(", ") {
             let entry = rules.entry(container_bag.clone()).or_insert(Vec::new());
             let Some((amount, bag)) = bag.split_once(' ') else {
-                panic!("Malformed input, couldn't separate amount from the contained bag description");
+                panic!(
+                    "Malformed input, couldn't separate amount from the contained bag description"
+                );
             };
             let Ok(amount) = amount.parse::<usize>() else {
                 continue;
@@ -56,7 +58,7 @@ fn reverse_rules(rules: &Rules) -> Rules {
 
     for (container_bag, contained_bags) in rules.iter() {
         for bag in contained_bags.iter() {
-            let entry = reverse.entry(container_bag.clone()).or_insert(Vec::new());
+            let entry = reverse.entry(container_bag.clone()).or_default();
             entry.push(bag.clone())
         }
     }
@@ -99,28 +101,11 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(count_bags(&rules, &our_bag))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 7, usize, usize);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(4));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(32));
-
-        let input = include_str!("../examples/07-2.txt");
-        assert_eq!(part_two(input), Some(126));
-    }
+    advent_of_code::example_tests!(2020, 7);
 }
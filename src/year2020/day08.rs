@@ -1,3 +1,4 @@
+use advent_of_code::{error::parse_in, SolveError};
 use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy)]
@@ -8,41 +9,38 @@ enum InstructionKind {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Instruction {
+pub struct Instruction {
     kind: InstructionKind,
     value: isize,
 }
 
 impl Instruction {
-    fn parse(line: &str) -> Self {
+    fn parse(input: &str, line: &str) -> Result<Self, SolveError> {
         let (instruction, amount) = line
             .split_once(' ')
-            .expect("Could not find a space between the instruction and the amount");
+            .ok_or_else(|| SolveError::parse(input, line, "expected \"<instruction> <amount>\""))?;
 
-        let value = amount
-            .parse()
-            .expect("Could not parse value of the instruction");
+        let value = parse_in(input, amount)?;
         let kind = match instruction {
             "nop" => InstructionKind::Nop,
             "acc" => InstructionKind::Acc,
             "jmp" => InstructionKind::Jmp,
-            _ => unreachable!(),
+            _ => return Err(SolveError::parse(input, instruction, "unknown instruction")),
         };
 
-        Self { kind, value }
+        Ok(Self { kind, value })
     }
 }
 
 #[derive(Debug, Clone)]
-struct Program {
+pub struct Program {
     instructions: Vec<Instruction>,
     pointer: isize,
     accumulator: isize,
 }
 
 impl Program {
-    fn parse(input: &str) -> Self {
-        let instructions = input.lines().map(Instruction::parse).collect();
+    pub fn new(instructions: Vec<Instruction>) -> Self {
         Self {
             instructions,
             pointer: 0,
@@ -50,7 +48,7 @@ impl Program {
         }
     }
 
-    fn run_to_end(&mut self) -> Option<isize> {
+    pub fn run_to_end(&mut self) -> Option<isize> {
         let mut seen: HashSet<isize> = HashSet::default();
 
         while self.pointer < self.instructions.len() as isize {
@@ -71,14 +69,21 @@ impl Program {
     }
 }
 
-pub fn part_one(input: &str) -> Option<isize> {
-    let mut program = Program::parse(input);
+pub fn parse(input: &str) -> Result<Vec<Instruction>, SolveError> {
+    input
+        .lines()
+        .map(|line| Instruction::parse(input, line))
+        .collect()
+}
+
+pub fn part_one(instructions: &[Instruction]) -> Option<isize> {
+    let mut program = Program::new(instructions.to_vec());
     program.run_to_end();
     Some(program.accumulator)
 }
 
-pub fn part_two(input: &str) -> Option<isize> {
-    let program = Program::parse(input);
+pub fn part_two(instructions: &[Instruction]) -> Option<isize> {
+    let program = Program::new(instructions.to_vec());
 
     (0..program.instructions.len()).find_map(|i| {
         let mut test_program = program.clone();
@@ -93,11 +98,7 @@ pub fn part_two(input: &str) -> Option<isize> {
     })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 8, Vec<Instruction> => isize, isize);
 
 #[cfg(test)]
 mod tests {
@@ -105,13 +106,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_one(&input), Some(5));
+        let input = advent_of_code::read_file("examples", 2020, 8);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
-        assert_eq!(part_two(&input), Some(8));
+        let input = advent_of_code::read_file("examples", 2020, 8);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(8));
     }
 }
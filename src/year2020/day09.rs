@@ -0,0 +1,265 @@
+use advent_of_code::{error::parse_in, SolveError};
+use std::{
+    collections::{HashSet, VecDeque},
+    ops::RangeInclusive,
+};
+
+/// The preamble length and the numbers after it.
+fn parse(input: &str) -> Result<(usize, Vec<usize>), SolveError> {
+    // NOTE: I edited by input files so that the first line is the preamble length
+    let mut lines = input.lines();
+    let preamble_length = lines
+        .next()
+        .ok_or_else(|| SolveError::invalid("expected the preamble length on the first line"))?;
+    let numbers = lines
+        .map(|line| parse_in(input, line))
+        .collect::<Result<Vec<usize>, _>>()?;
+    Ok((parse_in(input, preamble_length)?, numbers))
+}
+
+/// The first number that is not the sum of two of the `preamble_length` numbers before it.
+fn first_invalid(preamble_length: usize, numbers: &[usize]) -> Option<usize> {
+    let mut queue: VecDeque<usize> = VecDeque::with_capacity(preamble_length);
+
+    for (i, &current_number) in numbers.iter().enumerate() {
+        if i < preamble_length {
+            queue.push_back(current_number);
+            continue;
+        }
+
+        let mut found = false;
+        let mut complements: HashSet<usize> = Default::default();
+
+        for number in &queue {
+            if number > &current_number {
+                continue;
+            }
+
+            if complements.contains(&(current_number - number)) {
+                found = true;
+                break;
+            }
+            complements.insert(*number);
+        }
+
+        if found {
+            queue.pop_front();
+            queue.push_back(current_number);
+        } else {
+            return Some(current_number);
+        }
+    }
+
+    None
+}
+
+pub fn part_one(input: &str) -> Result<isize, SolveError> {
+    let (preamble_length, numbers) = parse(input)?;
+    first_invalid(preamble_length, &numbers)
+        .map(|number| number as isize)
+        .ok_or(SolveError::Unsolved)
+}
+
+pub fn part_two(input: &str) -> Result<isize, SolveError> {
+    let (preamble_length, numbers) = parse(input)?;
+    let Some(solution) = first_invalid(preamble_length, &numbers) else {
+        return Err(SolveError::invalid(
+            "every number is the sum of two before it, so there is no sum to look for",
+        ));
+    };
+    let solution = solution as isize;
+    let lines: Vec<isize> = numbers.iter().map(|&number| number as isize).collect();
+
+    // sliding window over the numbers, keeping the longest range of at least two that adds up.
+    let mut left = 0;
+    let mut current_sum: isize = 0;
+    let mut max_range: Option<RangeInclusive<usize>> = None;
+
+    for (right, number) in lines.iter().enumerate() {
+        current_sum += number;
+        while current_sum > solution {
+            current_sum -= lines[left];
+            left += 1;
+        }
+
+        let is_longer = |range: &RangeInclusive<usize>| right - left > range.end() - range.start();
+        if current_sum == solution && right > left && max_range.as_ref().is_none_or(is_longer) {
+            max_range = Some(left..=right);
+        }
+    }
+
+    let mut min_value = isize::MAX;
+    let mut max_value = 0;
+
+    for i in max_range.ok_or(SolveError::Unsolved)? {
+        min_value = min_value.min(lines[i]);
+        max_value = max_value.max(lines[i]);
+    }
+
+    Ok(min_value + max_value)
+}
+
+advent_of_code::solution!(2020, 9, isize, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2020, 9);
+        assert_eq!(part_one(&input), Ok(127));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2020, 9);
+        assert_eq!(part_two(&input), Ok(62));
+    }
+
+    fn render(preamble: usize, numbers: &[usize]) -> String {
+        let mut input = format!("{preamble}\n");
+        for number in numbers {
+            input.push_str(&format!("{number}\n"));
+        }
+        input
+    }
+
+    /// The first number that is not the sum of two of the `preamble` numbers before it.
+    fn brute_force_one(preamble: usize, numbers: &[usize]) -> Option<usize> {
+        (preamble..numbers.len())
+            .find(|&n| {
+                let window = &numbers[n - preamble..n];
+                !(0..window.len())
+                    .any(|i| (i + 1..window.len()).any(|j| window[i] + window[j] == numbers[n]))
+            })
+            .map(|n| numbers[n])
+    }
+
+    /// Tries every contiguous range of at least two numbers, preferring the longest.
+    fn brute_force_two(numbers: &[usize], target: usize) -> Option<usize> {
+        let mut best: Option<&[usize]> = None;
+        for start in 0..numbers.len() {
+            for end in start + 2..=numbers.len() {
+                let range = &numbers[start..end];
+                if range.iter().sum::<usize>() == target
+                    && best.is_none_or(|best| range.len() > best.len())
+                {
+                    best = Some(range);
+                }
+            }
+        }
+        best.map(|range| range.iter().min().unwrap() + range.iter().max().unwrap())
+    }
+
+    type Case = (usize, Vec<usize>);
+
+    /// A preamble, numbers that are valid, one that is the sum of a range, then noise.
+    fn generate(rng: &mut advent_of_code::rng::Rng) -> Case {
+        let preamble = rng.range(2..=5);
+        let mut numbers: Vec<usize> = (0..preamble).map(|_| rng.range(1..=30)).collect();
+        for _ in 0..rng.range(0..=8) {
+            let window = &numbers[numbers.len() - preamble..];
+            let i = rng.range(0..=preamble - 1);
+            let j = (i + rng.range(1..=preamble - 1)) % preamble;
+            numbers.push(window[i] + window[j]);
+        }
+        let start = rng.range(0..=numbers.len() - 2);
+        let end = rng.range(start + 1..=numbers.len() - 1);
+        numbers.push(numbers[start..=end].iter().sum());
+        numbers.extend((0..rng.range(0..=3)).map(|_| rng.range(1..=60)));
+        (preamble, numbers)
+    }
+
+    fn shrink((preamble, numbers): &Case) -> Vec<Case> {
+        use advent_of_code::property::{shrink_number, shrink_vec};
+
+        let mut candidates: Vec<Case> = shrink_vec(numbers)
+            .into_iter()
+            .map(|numbers| (*preamble, numbers))
+            .collect();
+        candidates.extend(
+            shrink_number(*preamble)
+                .into_iter()
+                .map(|preamble| (preamble, numbers.clone())),
+        );
+        for (i, &number) in numbers.iter().enumerate() {
+            candidates.extend(shrink_number(number).into_iter().map(|smaller| {
+                let mut numbers = numbers.clone();
+                numbers[i] = smaller;
+                (*preamble, numbers)
+            }));
+        }
+        candidates
+    }
+
+    /// Whether the puzzle's assumptions hold, the two-pointer window relies on positive numbers.
+    fn is_valid((preamble, numbers): &Case) -> bool {
+        *preamble >= 2 && numbers.len() > *preamble && !numbers.contains(&0)
+    }
+
+    #[test]
+    fn test_part_one_matches_brute_force() {
+        use advent_of_code::property::{check, expect_eq};
+
+        // caught the header line being counted into the preamble window.
+        check(generate, shrink, |case @ (preamble, numbers)| {
+            if !is_valid(case) {
+                return Ok(());
+            }
+            let expected = brute_force_one(*preamble, numbers);
+            expect_eq(
+                part_one(&render(*preamble, numbers)).ok(),
+                expected.map(|n| n as isize),
+            )
+        });
+    }
+
+    #[test]
+    fn test_part_two_matches_brute_force() {
+        use advent_of_code::property::{check, expect_eq};
+
+        // caught ranges ending at the last number being missed, and an answer being made up
+        // from the first number when no range adds up.
+        check(generate, shrink, |case @ (preamble, numbers)| {
+            // part two needs part one's answer.
+            let Some(target) = brute_force_one(*preamble, numbers).filter(|_| is_valid(case))
+            else {
+                return Ok(());
+            };
+            let expected = brute_force_two(numbers, target);
+            expect_eq(
+                part_two(&render(*preamble, numbers)).ok(),
+                expected.map(|n| n as isize),
+            )
+        });
+    }
+
+    #[test]
+    fn test_preamble_excludes_header() {
+        // with the header "2" in the window, 4 = 2 + 2 would count as valid.
+        assert_eq!(part_one(&render(2, &[1, 2, 3, 4])), Ok(4));
+    }
+
+    #[test]
+    fn test_range_ending_at_last_number() {
+        assert_eq!(part_two(&render(2, &[1, 2, 3, 10, 4, 6])), Ok(10));
+        assert_eq!(
+            part_two(&render(2, &[1, 2, 3, 10])),
+            Err(SolveError::Unsolved)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            part_one("2\n1\n2\n-3\n").unwrap_err().to_string(),
+            "parse error at line 4, column 1: invalid digit found in string (\"-3\")"
+        );
+        assert!(matches!(part_one(""), Err(SolveError::InvalidInput(_))));
+        assert!(matches!(
+            part_two(&render(2, &[1, 2, 3])),
+            Err(SolveError::InvalidInput(_))
+        ));
+    }
+}
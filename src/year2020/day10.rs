@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet};
+
+fn get_list_of_adapters(input: &str) -> Vec<isize> {
+    let mut adapters = input
+        .lines()
+        .map(|l| l.parse::<isize>())
+        .collect::<Result<Vec<_>, _>>()
+        .expect("Expected all the lines to be numbers");
+    adapters.sort();
+
+    adapters.insert(0, 0);
+    adapters.push(adapters.last().unwrap() + 3);
+
+    adapters
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let adapters = get_list_of_adapters(input);
+
+    let mut differences = [0; 3];
+    for i in 0..adapters.len() - 1 {
+        let small_adapter = adapters[i];
+        let big_adapter = adapters[i + 1];
+        differences[(big_adapter - small_adapter - 1) as usize] += 1;
+    }
+
+    Some(differences[0] * differences[2])
+}
+
+fn count_ways(
+    adapters: &HashSet<isize>,
+    adapter: isize,
+    cache: &mut HashMap<isize, isize>,
+) -> isize {
+    if let Some(&value) = cache.get(&adapter) {
+        return value;
+    }
+
+    let Some(current_adapter) = adapters.get(&adapter) else {
+        return 0;
+    };
+
+    if *current_adapter == 0 {
+        return 1;
+    }
+
+    let mut sum = 0;
+    for i in 1..=3 {
+        let next_to_check = current_adapter - i;
+        if next_to_check >= 0 {
+            let ways = count_ways(adapters, next_to_check, cache);
+            cache.insert(next_to_check, ways);
+            sum += ways;
+        }
+    }
+    sum
+}
+
+pub fn part_two(input: &str) -> Option<isize> {
+    let adapters = get_list_of_adapters(input);
+    let goal = adapters.last().cloned().unwrap();
+
+    let mut cache = HashMap::with_capacity(adapters.len());
+    let set = adapters.into_iter().collect::<HashSet<isize>>();
+
+    let ways = count_ways(&set, goal, &mut cache);
+
+    Some(ways)
+}
+
+advent_of_code::solution!(2020, 10, u32, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::example_tests!(2020, 10);
+
+    /// Counts arrangements by trying every subset of the adapters. The outlet and the device
+    /// are always part of the chain, so the highest adapter is too.
+    fn brute_force(adapters: &[isize]) -> isize {
+        let mut sorted = adapters.to_vec();
+        sorted.sort();
+        let highest = sorted.pop().unwrap();
+
+        (0..1u32 << sorted.len())
+            .filter(|subset| {
+                let mut chain = vec![0];
+                chain.extend(
+                    (0..sorted.len())
+                        .filter(|i| subset & (1 << i) != 0)
+                        .map(|i| sorted[i]),
+                );
+                chain.push(highest);
+                chain.windows(2).all(|pair| pair[1] - pair[0] <= 3)
+            })
+            .count() as isize
+    }
+
+    #[test]
+    fn test_part_two_matches_brute_force() {
+        use advent_of_code::property::{check, expect_eq, shrink_vec};
+
+        check(
+            |rng| {
+                let mut joltage = 0;
+                let mut adapters: Vec<isize> = (0..rng.range(1..=12))
+                    .map(|_| {
+                        joltage += rng.range(1..=3) as isize;
+                        joltage
+                    })
+                    .collect();
+                rng.shuffle(&mut adapters);
+                adapters
+            },
+            |adapters| shrink_vec(adapters),
+            |adapters| {
+                // like real inputs, every adapter has to fit into the chain.
+                let mut sorted = adapters.clone();
+                sorted.insert(0, 0);
+                sorted.sort();
+                if adapters.is_empty()
+                    || sorted
+                        .windows(2)
+                        .any(|pair| !(1..=3).contains(&(pair[1] - pair[0])))
+                {
+                    return Ok(());
+                }
+
+                let input: Vec<String> =
+                    adapters.iter().map(|adapter| adapter.to_string()).collect();
+                expect_eq(part_two(&input.join("\n")), Some(brute_force(adapters)))
+            },
+        );
+    }
+}
@@ -241,11 +241,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(occupied_seats)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 11, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -253,7 +249,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2020, 11);
         assert_eq!(part_one(&input), Some(37));
     }
 
@@ -269,7 +265,7 @@ mod tests {
         // #........
         // ...#....."#;
         //         assert_eq!(part_two(input), None);
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2020, 11);
         assert_eq!(part_two(&input), Some(26));
     }
 }
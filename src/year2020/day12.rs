@@ -196,11 +196,7 @@ pub fn part_two(input: &str) -> Option<isize> {
     Some(state.horizontal_distance.abs() + state.vertical_distance.abs())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 12, isize, isize);
 
 #[cfg(test)]
 mod tests {
@@ -208,13 +204,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 12);
+        let input = advent_of_code::read_file("examples", 2020, 12);
         assert_eq!(part_one(&input), Some(25));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 12);
+        let input = advent_of_code::read_file("examples", 2020, 12);
         assert_eq!(part_two(&input), Some(286));
     }
 }
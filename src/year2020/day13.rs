@@ -0,0 +1,161 @@
+use advent_of_code::{error::parse_in, SolveError};
+
+enum Bus {
+    X,
+    Id(usize),
+}
+
+impl Bus {
+    fn parse(input: &str, bus: &str) -> Result<Self, SolveError> {
+        match bus {
+            "x" => Ok(Bus::X),
+            id => match parse_in(input, id)? {
+                0 => Err(SolveError::parse(input, id, "expected a bus id above 0")),
+                id => Ok(Bus::Id(id)),
+            },
+        }
+    }
+}
+
+/// The earliest timestamp to depart at and the buses.
+fn parse(input: &str) -> Result<(usize, Vec<Bus>), SolveError> {
+    let mut lines = input.lines();
+    let (Some(timestamp), Some(buses)) = (lines.next(), lines.next()) else {
+        return Err(SolveError::invalid(
+            "expected a timestamp and a line of buses",
+        ));
+    };
+
+    let buses = buses
+        .split(',')
+        .map(|bus| Bus::parse(input, bus))
+        .collect::<Result<Vec<_>, _>>()?;
+    if buses.iter().all(|bus| matches!(bus, Bus::X)) {
+        return Err(SolveError::invalid("expected at least one bus in service"));
+    }
+    Ok((parse_in(input, timestamp)?, buses))
+}
+
+pub fn part_one(input: &str) -> Result<usize, SolveError> {
+    let (original_timestamp, buses) = parse(input)?;
+    let buses: Vec<usize> = buses
+        .into_iter()
+        .filter_map(|b| match b {
+            Bus::X => None,
+            Bus::Id(id) => Some(id),
+        })
+        .collect();
+
+    let mut current_timestamp = original_timestamp;
+    loop {
+        for id in &buses {
+            if current_timestamp % id == 0 {
+                return Ok((current_timestamp - original_timestamp) * id);
+            }
+        }
+        current_timestamp += 1;
+    }
+}
+
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    let (_original_timestamp, buses) = parse(input)?;
+
+    let mut current_timestamp = 1;
+    let mut wait_time = 1;
+
+    for (i, bus) in buses.iter().enumerate() {
+        let Bus::Id(id) = bus else { continue };
+        loop {
+            if (current_timestamp + i) % id == 0 {
+                wait_time *= id;
+                break;
+            }
+            current_timestamp += wait_time;
+        }
+    }
+
+    Ok(current_timestamp)
+}
+
+advent_of_code::solution!(2020, 13, usize, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2020, 13);
+        assert_eq!(part_one(&input), Ok(295));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2020, 13);
+        assert_eq!(part_two(&input), Ok(1068781));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            part_one("939\n7,x,13,?\n").unwrap_err().to_string(),
+            "parse error at line 2, column 8: invalid digit found in string (\"?\")"
+        );
+        assert_eq!(
+            part_two("939\n7,0\n").unwrap_err().to_string(),
+            "parse error at line 2, column 3: expected a bus id above 0 (\"0\")"
+        );
+        assert_eq!(
+            part_one("939\nx,x\n").unwrap_err(),
+            SolveError::invalid("expected at least one bus in service")
+        );
+        assert!(part_one("939\n").is_err());
+    }
+
+    /// Bus slots, `None` for an `x`. Ids are distinct primes, like in real inputs.
+    fn render(buses: &[Option<usize>]) -> String {
+        let buses: Vec<String> = buses
+            .iter()
+            .map(|bus| bus.map_or("x".into(), |id| id.to_string()))
+            .collect();
+        format!("939\n{}\n", buses.join(","))
+    }
+
+    /// Tries every timestamp until all buses depart at their offsets.
+    fn brute_force(buses: &[Option<usize>]) -> usize {
+        // like the sieve, only positive timestamps are considered.
+        (1..)
+            .find(|t| {
+                buses
+                    .iter()
+                    .enumerate()
+                    .all(|(i, bus)| bus.is_none_or(|id| (t + i) % id == 0))
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn test_part_two_matches_brute_force() {
+        use advent_of_code::property::{check, expect_eq, shrink_vec};
+
+        check(
+            |rng| {
+                let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+                rng.shuffle(&mut primes);
+                let mut buses = Vec::new();
+                for &id in &primes[..rng.range(1..=4)] {
+                    buses.extend((0..rng.range(0..=3)).map(|_| None));
+                    buses.push(Some(id));
+                }
+                buses
+            },
+            |buses| shrink_vec(buses),
+            |buses| {
+                if buses.iter().all(Option::is_none) {
+                    return Ok(());
+                }
+                expect_eq(part_two(&render(buses)), Ok(brute_force(buses)))
+            },
+        );
+    }
+}
@@ -84,25 +84,11 @@ pub fn part_two(input: &str) -> Option<u64> {
     Some(memory.values().sum())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 14, u64, u64);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
-        assert_eq!(part_one(&input), Some(165));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = include_str!("../examples/14-2.txt");
-        assert_eq!(part_two(input), Some(208));
-    }
+    advent_of_code::example_tests!(2020, 14);
 }
@@ -77,11 +77,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(last_spoken)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 15, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -89,13 +85,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 15);
+        let input = advent_of_code::read_file("examples", 2020, 15);
         assert_eq!(part_one(&input), Some(436));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 15);
+        let input = advent_of_code::read_file("examples", 2020, 15);
         assert_eq!(part_two(&input), Some(175594));
     }
 }
@@ -150,11 +150,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 16, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -162,7 +158,7 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 16);
+        let input = advent_of_code::read_file("examples", 2020, 16);
         assert_eq!(part_one(&input), Some(71));
     }
 
@@ -170,7 +166,7 @@ mod tests {
     fn test_part_two() {
         // This day doesn't provide the right value for the end of the puzzle
         // just the fields that I should have found
-        // let input = include_str!("../examples/16-2.txt");
+        // let input = include_str!("../examples/2020/16-2.txt");
         // assert_eq!(part_two(input), None);
     }
 }
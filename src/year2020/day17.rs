@@ -64,7 +64,6 @@ pub fn part_one(input: &str) -> Option<usize> {
     let mut active_cubes = parse_starting_active_cubes(input);
 
     for _ in 0..6 {
-        dbg!(active_cubes.len());
         active_cubes = step_simulation(&active_cubes);
     }
 
@@ -137,18 +136,13 @@ pub fn part_two(input: &str) -> Option<usize> {
     let mut active_cubes = parse_starting_active_cubes_two(input);
 
     for _ in 0..6 {
-        dbg!(active_cubes.len());
         active_cubes = step_simulation_two(&active_cubes);
     }
 
     Some(active_cubes.len())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 17, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -156,13 +150,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = advent_of_code::read_file("examples", 2020, 17);
         assert_eq!(part_one(&input), Some(112));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = advent_of_code::read_file("examples", 2020, 17);
         assert_eq!(part_two(&input), Some(848));
     }
 }
@@ -60,7 +60,7 @@ pub fn part_one(input: &str) -> Option<usize> {
         let mut iterator = line
             .as_bytes()
             .iter()
-            .chain(&[b')'])
+            .chain(b")")
             .filter(|&&b| b != b' ')
             .copied()
             .peekable();
@@ -77,7 +77,7 @@ pub fn part_two(input: &str) -> Option<usize> {
         let mut iterator = line
             .as_bytes()
             .iter()
-            .chain(&[b')'])
+            .chain(b")")
             .filter(|&&b| b != b' ')
             .copied()
             .peekable();
@@ -88,39 +88,11 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(sum)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 18);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 18, usize, usize);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let expected_results = [71, 26, 437, 12240, 13632];
-        let mut input = advent_of_code::read_file("examples", 18).to_string();
-
-        input.push_str("\n2 * 3 + (4 * 5)");
-        input.push_str("\n5 + (8 * 3 + 9 + 3 * 4 * 3)");
-        input.push_str("\n5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))");
-        input.push_str("\n((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2");
-
-        assert_eq!(part_one(&input), Some(expected_results.iter().sum()))
-    }
-
-    #[test]
-    fn test_part_two() {
-        let expected_results = [231, 51, 46, 1445, 669060, 23340];
-        let mut input = advent_of_code::read_file("examples", 18);
-
-        input.push_str("\n1 + (2 * 3) + (4 * (5 + 6))");
-        input.push_str("\n2 * 3 + (4 * 5)");
-        input.push_str("\n5 + (8 * 3 + 9 + 3 * 4 * 3)");
-        input.push_str("\n5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))");
-        input.push_str("\n((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2");
-        assert_eq!(part_two(&input), Some(expected_results.iter().sum()));
-    }
+    advent_of_code::example_tests!(2020, 18);
 }
@@ -141,28 +141,11 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(count)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 19);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 19, usize, u32);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_one(&input), Some(2));
-
-        let input = include_str!("../examples/19-2.txt");
-        assert_eq!(part_one(input), Some(3));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = include_str!("../examples/19-2.txt");
-        assert_eq!(part_two(input), Some(12));
-    }
+    advent_of_code::example_tests!(2020, 19);
 }
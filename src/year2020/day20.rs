@@ -1,3 +1,4 @@
+use advent_of_code::{error::parse_in, SolveError};
 use std::{collections::HashSet, fmt::Debug};
 
 const TILE_SIZE: usize = 10;
@@ -7,13 +8,24 @@ const MONSTER: [&str; 3] = [
     " #  #  #  #  #  #   ",
 ];
 
+/// A tile of the image in one of its orientations.
 #[derive(Clone)]
-struct Tile {
+pub struct Tile {
     id: usize,
     pixels: Vec<Vec<u8>>,
 }
 
 impl Tile {
+    /// The number from the tile's `Tile <id>:` header, shared by all its orientations.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Rows of `#` and `.` bytes, borders included.
+    pub fn pixels(&self) -> &[Vec<u8>] {
+        &self.pixels
+    }
+
     fn empty() -> Self {
         Self {
             id: 0,
@@ -21,15 +33,32 @@ impl Tile {
         }
     }
 
-    fn parse(input: &str) -> Self {
-        let mut lines = input.lines();
-        let tile_info = lines.next().unwrap();
-        let id = tile_info[5..tile_info.len() - 1].parse().unwrap();
-
-        Self {
-            id,
-            pixels: lines.map(|l| l.as_bytes().to_vec()).collect(),
+    /// A tile from its `Tile <id>:` header and rows, `tile` is a slice of `input`.
+    pub fn parse(input: &str, tile: &str) -> Result<Self, SolveError> {
+        let mut lines = tile.lines();
+        let header = lines.next().unwrap_or(tile);
+        let id = header
+            .strip_prefix("Tile ")
+            .and_then(|id| id.strip_suffix(':'))
+            .ok_or_else(|| SolveError::parse(input, header, "expected \"Tile <id>:\""))?;
+
+        let mut pixels = Vec::with_capacity(TILE_SIZE);
+        for line in lines {
+            if line.len() != TILE_SIZE || !line.bytes().all(|b| b == b'#' || b == b'.') {
+                let reason = format!("expected {TILE_SIZE} pixels of '#' or '.'");
+                return Err(SolveError::parse(input, line, reason));
+            }
+            pixels.push(line.as_bytes().to_vec());
         }
+        if pixels.len() != TILE_SIZE {
+            let reason = format!("expected {TILE_SIZE} rows, got {}", pixels.len());
+            return Err(SolveError::parse(input, header, reason));
+        }
+
+        Ok(Self {
+            id: parse_in(input, id)?,
+            pixels,
+        })
     }
 
     fn can_connect_below(&self, other: &Tile) -> bool {
@@ -74,7 +103,8 @@ fn flip(image: &mut [Vec<u8>]) {
     }
 }
 
-struct Grid {
+/// The square the tiles are arranged in, filled in by [`Grid::search`].
+pub struct Grid {
     layout: Vec<Vec<Tile>>,
     size: usize,
     solution: Option<Vec<Vec<Tile>>>,
@@ -93,7 +123,8 @@ impl Debug for Grid {
 }
 
 impl Grid {
-    fn new(tiles: &[Tile]) -> Self {
+    /// An empty grid for `tiles`, which hold every orientation of each tile.
+    pub fn new(tiles: &[Tile]) -> Self {
         // 8 are the 4 possible rotations * 2 flips
         let size = ((tiles.len() / 8) as f64).sqrt() as usize;
 
@@ -104,11 +135,20 @@ impl Grid {
         }
     }
 
-    fn parse_tiles(input: &str) -> Vec<Tile> {
-        let mut tiles = Vec::new();
+    /// Every orientation of every tile in `input`.
+    pub fn parse_tiles(input: &str) -> Result<Vec<Tile>, SolveError> {
+        let tilemaps: Vec<&str> = input.trim_end().split("\n\n").collect();
+        let size = (tilemaps.len() as f64).sqrt() as usize;
+        if size * size != tilemaps.len() {
+            return Err(SolveError::invalid(format!(
+                "expected a square number of tiles, got {}",
+                tilemaps.len()
+            )));
+        }
 
-        for tilemap in input.split("\n\n") {
-            let mut tile = Tile::parse(tilemap);
+        let mut tiles = Vec::new();
+        for tilemap in tilemaps {
+            let mut tile = Tile::parse(input, tilemap)?;
             for _flips in 0..2 {
                 for _rotations in 0..4 {
                     tiles.push(tile.clone());
@@ -117,10 +157,12 @@ impl Grid {
                 flip(&mut tile.pixels);
             }
         }
-        tiles
+        Ok(tiles)
     }
 
-    fn search(&mut self, row: usize, col: usize, tiles: &[Tile], visited: &mut HashSet<usize>) {
+    /// Tries the tiles from position (`row`, `col`) onwards, keeping the first complete
+    /// arrangement. `visited` holds the ids of the tiles placed so far.
+    pub fn search(&mut self, row: usize, col: usize, tiles: &[Tile], visited: &mut HashSet<usize>) {
         // Backtracking base case
         if row == self.size {
             self.solution = Some(self.layout.clone());
@@ -154,17 +196,29 @@ impl Grid {
             visited.remove(&tile.id);
         }
     }
+
+    /// Number of tiles along each side.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The arrangement found by [`Grid::search`], rows of tiles.
+    pub fn solution(&self) -> Option<&[Vec<Tile>]> {
+        self.solution.as_deref()
+    }
 }
 
-pub fn part_one(input: &str) -> Option<usize> {
-    let tiles = Grid::parse_tiles(input);
-    let mut grid = Grid::new(&tiles);
+/// Every orientation of every tile.
+pub fn parse(input: &str) -> Result<Vec<Tile>, SolveError> {
+    Grid::parse_tiles(input)
+}
+
+pub fn part_one(tiles: &[Tile]) -> Option<usize> {
+    let mut grid = Grid::new(tiles);
 
-    grid.search(0, 0, &tiles, &mut HashSet::new());
+    grid.search(0, 0, tiles, &mut HashSet::new());
 
-    let Some(solution) = grid.solution else {
-        return None;
-    };
+    let solution = grid.solution?;
 
     let top_left = &solution[0][0];
     let top_right = &solution[0][grid.size - 1];
@@ -232,15 +286,12 @@ fn try_marking_monster(picture: &mut [Vec<u8>], start_row: usize, start_col: usi
     true
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
-    let tiles = Grid::parse_tiles(input);
-    let mut grid = Grid::new(&tiles);
+pub fn part_two(tiles: &[Tile]) -> Option<usize> {
+    let mut grid = Grid::new(tiles);
 
-    grid.search(0, 0, &tiles, &mut HashSet::new());
+    grid.search(0, 0, tiles, &mut HashSet::new());
 
-    let Some(solution) = grid.solution else {
-        return None;
-    };
+    let solution = grid.solution?;
 
     let mut picture = assemble_picture(&solution, grid.size);
     count_monsters(&mut picture);
@@ -253,11 +304,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(sea_harshness)
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 20, Vec<Tile> => usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -265,13 +312,49 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20);
-        assert_eq!(part_one(&input), Some(20899048083289));
+        let input = advent_of_code::read_file("examples", 2020, 20);
+        assert_eq!(part_one(&parse(&input).unwrap()), Some(20899048083289));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20);
-        assert_eq!(part_two(&input), Some(273));
+        let input = advent_of_code::read_file("examples", 2020, 20);
+        assert_eq!(part_two(&parse(&input).unwrap()), Some(273));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = advent_of_code::read_file("examples", 2020, 20);
+        let error = |input: &str| parse(input).err().unwrap().to_string();
+
+        assert_eq!(
+            error(&input.replacen("Tile 2311:", "Tile 23x1:", 1)),
+            "parse error at line 1, column 6: invalid digit found in string (\"23x1\")"
+        );
+        assert_eq!(
+            error(&input.replacen("..##.#..#.", "..##.#..#", 1)),
+            "parse error at line 2, column 1: expected 10 pixels of '#' or '.' (\"..##.#..#\")"
+        );
+        assert_eq!(
+            error(&input.replacen("Tile 2311:", "Tyle 2311:", 1)),
+            "parse error at line 1, column 1: expected \"Tile <id>:\" (\"Tyle 2311:\")"
+        );
+        let (_, eight_tiles) = input.split_once("\n\n").unwrap();
+        assert_eq!(
+            error(eight_tiles),
+            "invalid input: expected a square number of tiles, got 8"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = advent_of_code::read_file("examples", 2020, 20);
+        let tiles = parse(&input).unwrap();
+
+        let ids: HashSet<usize> = tiles.iter().map(Tile::id).collect();
+        assert_eq!(ids.len(), 9);
+        assert!(ids.contains(&2311));
+        assert!(tiles.iter().all(|tile| tile.pixels().len() == TILE_SIZE
+            && tile.pixels().iter().all(|row| row.len() == TILE_SIZE)));
     }
 }
@@ -26,7 +26,7 @@ fn find_allergens(input: &str) -> (HashMap<&str, usize>, HashMap<&str, &str>) {
             for allergen in allergens.split(", ") {
                 list_with_allergens
                     .entry(allergen)
-                    .or_insert(Vec::new())
+                    .or_default()
                     .push(ingredients.clone())
             }
         });
@@ -88,11 +88,7 @@ pub fn part_two(input: &str) -> Option<String> {
     )
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 21);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 21, usize, String);
 
 #[cfg(test)]
 mod tests {
@@ -100,13 +96,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 21);
+        let input = advent_of_code::read_file("examples", 2020, 21);
         assert_eq!(part_one(&input), Some(5));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 21);
+        let input = advent_of_code::read_file("examples", 2020, 21);
         assert_eq!(part_two(&input), Some("mxmxvkd,sqjhc,fvjkl".to_string()));
     }
 }
@@ -1,17 +1,17 @@
 use std::collections::{HashSet, VecDeque};
 
-enum Winner {
+pub enum Winner {
     Player1,
     Player2,
 }
 
-struct Game {
+pub struct Game {
     player_1: VecDeque<usize>,
     player_2: VecDeque<usize>,
 }
 
 impl Game {
-    fn parse(input: &str) -> Self {
+    pub fn parse(input: &str) -> Self {
         let player_decks = input.trim().split("\n\n").collect::<Vec<_>>();
 
         Self {
@@ -135,7 +135,7 @@ impl Game {
         }
     }
 
-    fn play_to_end(&mut self) -> Winner {
+    pub fn play_to_end(&mut self) -> Winner {
         while !self.player_1.is_empty() && !self.player_2.is_empty() {
             self.play_turn();
         }
@@ -151,7 +151,7 @@ impl Game {
         deck.iter()
             .rev()
             .enumerate()
-            .map(|(i, card)| (card * (i + 1)))
+            .map(|(i, card)| card * (i + 1))
             .sum()
     }
 }
@@ -176,11 +176,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     }
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 22);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 22, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -188,13 +184,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 22);
+        let input = advent_of_code::read_file("examples", 2020, 22);
         assert_eq!(part_one(&input), Some(306));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 22);
+        let input = advent_of_code::read_file("examples", 2020, 22);
         assert_eq!(part_two(&input), Some(291));
     }
 }
@@ -0,0 +1,258 @@
+use advent_of_code::SolveError;
+
+/// The circle of cups, as a list in which each cup's label indexes the label of the next cup.
+pub struct Game {
+    current: usize,
+    circle: Vec<usize>,
+}
+
+/// The cup labels in `input`, which must be the digits from 1 up to the number of cups.
+fn parse_cups(input: &str) -> Result<Vec<usize>, SolveError> {
+    let line = input.trim();
+    let cups = line
+        .char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(label @ 1..) => Ok(label as usize),
+            _ => Err(SolveError::parse(
+                input,
+                &line[i..i + c.len_utf8()],
+                "expected a cup label from 1 to 9",
+            )),
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    // a move picks up three cups and needs a destination besides the current cup.
+    if cups.len() < 5 {
+        return Err(SolveError::invalid("expected at least 5 cups"));
+    }
+    let mut sorted = cups.clone();
+    sorted.sort_unstable();
+    if !sorted.iter().copied().eq(1..=cups.len()) {
+        return Err(SolveError::invalid(format!(
+            "expected each label from 1 to {} once",
+            cups.len()
+        )));
+    }
+    Ok(cups)
+}
+
+impl Game {
+    /// The circle with just the cups from `input`.
+    pub fn parse(input: &str) -> Result<Self, SolveError> {
+        let cups = parse_cups(input)?;
+
+        // The index of the cups is their actual value, the value the index holds is the cup they point to.
+        // So using example "3->8->9->1->2->5->4->6->7" (and 7 wraps around to 3)
+
+        // There's an extra zero at the start so we don't have to wrap the index around on every operation
+        // Inserting them on their proper spot we'll end up with
+        // [0, 1, 2, 3, 4, 5, 6, 7, 8, 9] <- their index
+        // [0, 2, 5, 8, 6, 4, 7, 3, 9, 1] <- values they point to (the actual result)
+        let mut circle = vec![0; cups.len() + 1];
+        for i in 0..cups.len() {
+            circle[cups[i]] = cups[(i + 1) % cups.len()];
+        }
+
+        Ok(Self {
+            current: cups[0],
+            circle,
+        })
+    }
+
+    /// The circle of part two, the cups from `input` followed by the rest up to one million.
+    pub fn parse_extended(input: &str) -> Result<Self, SolveError> {
+        let cups = parse_cups(input)?;
+
+        let mut circle = vec![0; 1_000_000 + 1];
+        for i in 0..cups.len() - 1 {
+            circle[cups[i]] = cups[i + 1];
+        }
+
+        // Make last cup in the input point to the value of the first extended cup
+        // in this case the max cup was 9 so the next cup is going to be 10
+        let last_cup = cups[cups.len() - 1];
+        let max_cup = cups.len();
+        circle[last_cup] = max_cup + 1;
+
+        for (i, cup) in circle
+            .iter_mut()
+            .enumerate()
+            .take(1_000_000)
+            .skip(max_cup + 1)
+        {
+            *cup = i + 1;
+        }
+        // Make the last extended value wrap around to the start of input cups
+        *circle.last_mut().unwrap() = cups[0];
+
+        Ok(Self {
+            current: cups[0],
+            circle,
+        })
+    }
+
+    /// Plays one move.
+    pub fn step(&mut self) {
+        let a = self.circle[self.current];
+        let b = self.circle[a];
+        let c = self.circle[b];
+
+        let mut destination = self.current - 1;
+        if destination == 0 {
+            destination = self.circle.len() - 1;
+        }
+        // Check to see if what was going to be the next destination was one of the cups you just picked up
+        // if it was pick the highest in the circle which is always the last element
+        while [a, b, c].contains(&destination) {
+            destination = destination.saturating_sub(1);
+            if destination == 0 {
+                destination = self.circle.len() - 1;
+            }
+        }
+
+        // Now we're ready to assign the new order to the cups
+        // Since we removed 3 cups after the current one, the current should now point to what `c` was pointing to
+        // The cups we picked up get moved just after the destination so the `destination cup` points to `a`
+        // and `c` should now point to what `destination` was pointing to.
+        self.circle[self.current] = self.circle[c];
+        let temp = self.circle[destination];
+        self.circle[destination] = a;
+        self.circle[c] = temp;
+
+        // Finally the new current is what current was pointing to.
+        self.current = self.circle[self.current];
+    }
+
+    /// The labels after cup 1, going around the circle.
+    pub fn stringify_positions(&self) -> String {
+        let mut result = String::new();
+
+        let mut i = 1;
+        for _ in 0..self.circle.len() - 2 {
+            result.push_str(&self.circle[i].to_string());
+            i = self.circle[i];
+        }
+
+        result
+    }
+}
+
+pub fn part_one(input: &str) -> Result<String, SolveError> {
+    let mut game = Game::parse(input)?;
+
+    for _ in 0..100 {
+        game.step();
+    }
+
+    Ok(game.stringify_positions())
+}
+
+pub fn part_two(input: &str) -> Result<usize, SolveError> {
+    let mut game = Game::parse_extended(input)?;
+
+    for _ in 0..10_000_000 {
+        game.step();
+    }
+
+    let result = game.circle[1] * game.circle[game.circle[1]];
+
+    Ok(result)
+}
+
+advent_of_code::solution!(2020, 23, String, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2020, 23);
+        assert_eq!(part_one(&input), Ok("67384529".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2020, 23);
+        assert_eq!(part_two(&input), Ok(149245887792));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            part_one("38x\n").unwrap_err().to_string(),
+            "parse error at line 1, column 3: expected a cup label from 1 to 9 (\"x\")"
+        );
+        assert_eq!(
+            part_one("3412").unwrap_err(),
+            SolveError::invalid("expected at least 5 cups")
+        );
+        assert_eq!(
+            part_one("123457").unwrap_err(),
+            SolveError::invalid("expected each label from 1 to 6 once")
+        );
+    }
+
+    /// Plays the moves as described in the puzzle, on a plain list of cup labels.
+    fn brute_force(cups: &[usize], moves: usize) -> String {
+        let mut circle = cups.to_vec();
+        let max = *circle.iter().max().unwrap();
+
+        for _ in 0..moves {
+            // the current cup is always kept at the front.
+            let current = circle[0];
+            let picked: Vec<usize> = circle.drain(1..4).collect();
+            let mut destination = current;
+            loop {
+                destination = if destination == 1 {
+                    max
+                } else {
+                    destination - 1
+                };
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+            let position = circle.iter().position(|&cup| cup == destination).unwrap();
+            circle.splice(position + 1..position + 1, picked);
+            circle.rotate_left(1);
+        }
+
+        let one = circle.iter().position(|&cup| cup == 1).unwrap();
+        circle.rotate_left(one);
+        circle[1..].iter().map(|cup| cup.to_string()).collect()
+    }
+
+    #[test]
+    fn test_step_matches_brute_force() {
+        use advent_of_code::property::{check, expect_eq, shrink_number};
+
+        check(
+            |rng| {
+                let mut cups: Vec<usize> = (1..=rng.range(5..=9)).collect();
+                rng.shuffle(&mut cups);
+                (cups, rng.range(0..=100))
+            },
+            |(cups, moves)| {
+                let mut candidates: Vec<(Vec<usize>, usize)> = shrink_number(*moves)
+                    .into_iter()
+                    .map(|moves| (cups.clone(), moves))
+                    .collect();
+                // dropping the highest label keeps the cups a permutation of 1..=n.
+                if cups.len() > 5 {
+                    let fewer = cups.iter().copied().filter(|&cup| cup != cups.len());
+                    candidates.push((fewer.collect(), *moves));
+                }
+                candidates
+            },
+            |(cups, moves)| {
+                let input: String = cups.iter().map(|cup| cup.to_string()).collect();
+                let mut game = Game::parse(&input).unwrap();
+                for _ in 0..*moves {
+                    game.step();
+                }
+                expect_eq(game.stringify_positions(), brute_force(cups, *moves))
+            },
+        );
+    }
+}
@@ -12,10 +12,7 @@ impl TilePosition {
         let mut chars = line.chars();
         let mut position = (0, 0);
 
-        loop {
-            let Some(new_offset) = Self::match_char(&mut chars) else {
-                break;
-            };
+        while let Some(new_offset) = Self::match_char(&mut chars) {
             position.0 += new_offset.0;
             position.1 += new_offset.1;
         }
@@ -115,11 +112,7 @@ pub fn part_two(input: &str) -> Option<usize> {
     Some(lobby.black_tiles.len())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 24);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 24, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -129,13 +122,13 @@ mod tests {
     fn test_part_one() {
         let input = "nwwswee";
         assert_eq!(TilePosition::parse_line(input), TilePosition(0, 0));
-        let input = advent_of_code::read_file("examples", 24);
+        let input = advent_of_code::read_file("examples", 2020, 24);
         assert_eq!(part_one(&input), Some(10));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 24);
+        let input = advent_of_code::read_file("examples", 2020, 24);
         assert_eq!(part_two(&input), Some(2208));
     }
 }
@@ -33,15 +33,11 @@ pub fn part_one(input: &str) -> Option<usize> {
     Some(encryption_key)
 }
 
-pub fn part_two(input: &str) -> Option<usize> {
+pub fn part_two(_input: &str) -> Option<usize> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 25);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2020, 25, usize, usize);
 
 #[cfg(test)]
 mod tests {
@@ -49,13 +45,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 25);
+        let input = advent_of_code::read_file("examples", 2020, 25);
         assert_eq!(part_one(&input), Some(14897079));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 25);
+        let input = advent_of_code::read_file("examples", 2020, 25);
         assert_eq!(part_two(&input), None);
     }
 }
@@ -0,0 +1,42 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Checks every day against its real input and the answers recorded in `src/answers`.
+//! Days without either are reported as ignored, so the suite passes on a fresh clone.
+use advent_of_code::{answers::read_answers, input::has_input, input_path};
+use std::process::Command;
+
+/// Runs `cargo verify` for one day, skipping it if the input or answers are missing.
+fn check(year: u16, day: u8) {
+    // `cargo test -- --ignored` runs the ignored days as well, they have nothing to check.
+    let input = input_path("inputs", year, day);
+    if !has_input(&input) {
+        eprintln!(
+            "Skipping day {day:02}: no input in \"{}\".",
+            input.display()
+        );
+        return;
+    }
+    match read_answers(year, day) {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            eprintln!("Skipping day {day:02}: no recorded answers.");
+            return;
+        }
+        Err(e) => panic!("invalid answers file for day {day:02}: {e}"),
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(["--verify", "--year", &year.to_string(), &day.to_string()])
+        .output()
+        .expect("failed to run advent_of_code");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "day {day:02} of {year} does not match its recorded answers:\n{stdout}{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/golden.rs"));